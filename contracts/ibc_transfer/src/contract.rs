@@ -1,9 +1,10 @@
 use cosmwasm_std::{
-    coin, entry_point, Binary, CosmosMsg, Deps, DepsMut, Env, IbcMsg, IbcTimeout, IbcTimeoutBlock,
-    MessageInfo, Reply, Response, StdError, StdResult, SubMsg,
+    coin, entry_point, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdError, StdResult, Storage, SubMsg,
 };
-use neutron_sdk::interchain_txs::helpers::parse_sequence;
-use neutron_sdk::sudo::msg::{RequestPacket, SudoMsg};
+use neutron_sdk::bindings::msg::{IbcFee, MsgIbcTransferResponse, NeutronMsg};
+use neutron_sdk::interchain_txs::helpers::decode_message_response;
+use neutron_sdk::sudo::msg::{RequestPacket, RequestPacketTimeoutHeight, SudoMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,9 @@ use crate::state::{
     IBC_SUDO_ID_RANGE_END, IBC_SUDO_ID_RANGE_START,
 };
 
+// Default timeout for IbcTransfer is 10000000 blocks
+const DEFAULT_TIMEOUT_HEIGHT: u64 = 10000000;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct InstantiateMsg {}
 
@@ -29,15 +33,35 @@ pub fn instantiate(
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    Send { to: String, amount: u128 },
+    Send {
+        channel: String,
+        to: String,
+        denom: String,
+        amount: u128,
+        timeout_height: Option<u64>,
+        // the fee is paid by the contract to relayers for delivering the acknowledgement or the timeout
+        fee: IbcFee,
+    },
 }
 
 #[entry_point]
-pub fn execute(deps: DepsMut, _env: Env, _: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response<NeutronMsg>> {
     deps.api
         .debug(format!("WASMDEBUG: execute: received msg: {:?}", msg).as_str());
     match msg {
-        ExecuteMsg::Send { to, amount } => execute_send(deps, to, amount),
+        ExecuteMsg::Send {
+            channel,
+            to,
+            denom,
+            amount,
+            timeout_height,
+            fee,
+        } => execute_send(deps, channel, to, denom, amount, timeout_height, fee),
     }
 }
 
@@ -65,25 +89,37 @@ fn sudo_callback2(deps: Deps, payload: Type2) -> StdResult<Response> {
     Ok(Response::new())
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum SudoPayload {
     HandlerPayload1(Type1),
     HandlerPayload2(Type2),
 }
 
-fn msg_with_sudo_callback<C: Into<CosmosMsg>>(
+fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
     deps: DepsMut,
     msg: C,
     payload: SudoPayload,
-) -> StdResult<SubMsg> {
+) -> StdResult<SubMsg<T>> {
     let id = save_reply_payload(deps.storage, payload)?;
     Ok(SubMsg::reply_on_success(msg, id))
 }
 
 fn prepare_sudo_payload(mut deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
     let payload = read_reply_payload(deps.storage, msg.id)?;
-    let (channel_id, seq_id) = parse_sequence(deps.as_ref(), msg)?;
-    save_sudo_payload(deps.branch().storage, channel_id, seq_id, payload)?;
+    let resp: MsgIbcTransferResponse = decode_message_response(msg)?;
+    deps.api.debug(
+        format!(
+            "WASMDEBUG: prepare_sudo_payload: ibc transfer sent: {:?}",
+            resp
+        )
+        .as_str(),
+    );
+    save_sudo_payload(
+        deps.branch().storage,
+        resp.channel,
+        resp.sequence_id,
+        payload,
+    )?;
     Ok(Response::new())
 }
 
@@ -98,31 +134,45 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
     }
 }
 
-fn execute_send(mut deps: DepsMut, to: String, amount: u128) -> StdResult<Response> {
-    let coin1 = coin(amount, "stake");
-    let msg1: CosmosMsg = CosmosMsg::Ibc(IbcMsg::Transfer {
+fn execute_send(
+    mut deps: DepsMut,
+    channel: String,
+    to: String,
+    denom: String,
+    amount: u128,
+    timeout_height: Option<u64>,
+    fee: IbcFee,
+) -> StdResult<Response<NeutronMsg>> {
+    let msg1 = NeutronMsg::ibc_transfer(
+        "transfer".to_string(),
         // transfer channel
-        channel_id: "channel-0".to_string(),
+        channel.clone(),
+        coin(amount, denom.clone()),
         // "to" is an address on the counterpart chain
-        to_address: to.clone(),
-        amount: coin1,
-        timeout: IbcTimeout::with_block(IbcTimeoutBlock {
-            revision: 2,
-            height: 10000000,
-        }),
-    });
-    let coin2 = coin(2 * amount, "stake");
-    let msg2: CosmosMsg = CosmosMsg::Ibc(IbcMsg::Transfer {
+        to.clone(),
+        RequestPacketTimeoutHeight {
+            revision_number: Some(2),
+            revision_height: Some(timeout_height.unwrap_or(DEFAULT_TIMEOUT_HEIGHT)),
+        },
+        0,
+        "".to_string(),
+        fee.clone(),
+    );
+    let msg2 = NeutronMsg::ibc_transfer(
+        "transfer".to_string(),
         // transfer channel
-        channel_id: "channel-0".to_string(),
+        channel,
+        coin(2 * amount, denom),
         // "to" is an address on the counterpart chain
-        to_address: to,
-        amount: coin2,
-        timeout: IbcTimeout::with_block(IbcTimeoutBlock {
-            revision: 2,
-            height: 10000000,
-        }),
-    });
+        to,
+        RequestPacketTimeoutHeight {
+            revision_number: Some(2),
+            revision_height: Some(timeout_height.unwrap_or(DEFAULT_TIMEOUT_HEIGHT)),
+        },
+        0,
+        "".to_string(),
+        fee,
+    );
     let submsg1 = msg_with_sudo_callback(
         deps.branch(),
         msg1,
@@ -149,7 +199,12 @@ fn execute_send(mut deps: DepsMut, to: String, amount: u128) -> StdResult<Respon
 pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> StdResult<Response> {
     match msg {
         SudoMsg::Response { request, data } => sudo_response(deps, request, data),
-        _ => todo!(),
+        SudoMsg::Error { request, details } => sudo_error(deps, request, details),
+        SudoMsg::Timeout { request } => sudo_timeout(deps, request),
+        // the contract neither opens interchain accounts nor registers interchain queries
+        SudoMsg::OpenAck { .. } | SudoMsg::TxQueryResult { .. } | SudoMsg::KVQueryResult { .. } => {
+            Err(StdError::generic_err("unexpected sudo message"))
+        }
     }
 }
 
@@ -161,16 +216,47 @@ fn sudo_response(deps: DepsMut, req: RequestPacket, data: Binary) -> StdResult<R
         )
         .as_str(),
     );
+    match read_request_payload(deps.storage, req)? {
+        SudoPayload::HandlerPayload1(t1) => sudo_callback1(deps.as_ref(), t1),
+        SudoPayload::HandlerPayload2(t2) => sudo_callback2(deps.as_ref(), t2),
+    }
+    // at this place we can safely remove the data under (channel_id, seq_id) key
+    // but it costs an extra gas, so its on you how to use the storage
+}
+
+fn sudo_error(deps: DepsMut, req: RequestPacket, details: String) -> StdResult<Response> {
+    deps.api.debug(
+        format!(
+            "WASMDEBUG: sudo_error: sudo error received: {:?} {}",
+            req, details
+        )
+        .as_str(),
+    );
+    let payload = read_request_payload(deps.storage, req)?;
+    deps.api
+        .debug(format!("WASMDEBUG: sudo_error: sudo payload: {:?}", payload).as_str());
+    // the transfer has failed on the remote side and the funds were refunded,
+    // here is the place to revert the contract state changes made for the transfer
+    Ok(Response::new())
+}
+
+fn sudo_timeout(deps: DepsMut, req: RequestPacket) -> StdResult<Response> {
+    deps.api
+        .debug(format!("WASMDEBUG: sudo_timeout: sudo timeout received: {:?}", req).as_str());
+    let payload = read_request_payload(deps.storage, req)?;
+    deps.api
+        .debug(format!("WASMDEBUG: sudo_timeout: sudo payload: {:?}", payload).as_str());
+    // the transfer packet has timed out and the funds were refunded,
+    // here is the place to revert the contract state changes made for the transfer
+    Ok(Response::new())
+}
+
+fn read_request_payload(store: &mut dyn Storage, req: RequestPacket) -> StdResult<SudoPayload> {
     let seq_id = req
         .sequence
         .ok_or_else(|| StdError::generic_err("sequence not found"))?;
     let channel_id = req
         .source_channel
         .ok_or_else(|| StdError::generic_err("channel_id not found"))?;
-    match read_sudo_payload(deps.storage, channel_id, seq_id)? {
-        SudoPayload::HandlerPayload1(t1) => sudo_callback1(deps.as_ref(), t1),
-        SudoPayload::HandlerPayload2(t2) => sudo_callback2(deps.as_ref(), t2),
-    }
-    // at this place we can safely remove the data under (channel_id, seq_id) key
-    // but it costs an extra gas, so its on you how to use the storage
+    read_sudo_payload(store, channel_id, seq_id)
 }
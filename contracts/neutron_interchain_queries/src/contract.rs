@@ -18,7 +18,7 @@ use cosmos_sdk_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
 use cosmwasm_std::{
     entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
 };
-use cosmwasm_std::{from_binary, to_binary};
+use cosmwasm_std::{from_binary, to_binary, Uint128};
 use prost::Message as ProstMessage;
 
 use crate::msg::{
@@ -31,17 +31,30 @@ use crate::state::{
 };
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_sdk::bindings::query::{InterchainQueries, QueryRegisteredQueryResponse};
+use neutron_sdk::bindings::types::PageRequest;
 use neutron_sdk::interchain_queries::queries::{
-    query_balance, query_delegations, query_registered_query,
+    get_registered_queries, query_account, query_authz_grants, query_balance, query_bank_total,
+    query_delegations, query_delegator_rewards, query_denom_metadata, query_denom_traces,
+    query_fee_allowance, query_fresh_balance, query_fresh_delegations, query_gamm_pool,
+    query_government_proposal_votes, query_government_proposals, query_redelegations,
+    query_registered_query, query_unbonding_delegations, query_validators_info,
+    query_validators_signing_infos, query_wasm_contract_state, RegisteredQueriesFilter,
 };
 use neutron_sdk::interchain_queries::{
-    register_balance_query, register_delegator_delegations_query, register_transfers_query,
-    remove_interchain_query, update_interchain_query,
+    register_account_query, register_authz_grants_query, register_balance_query,
+    register_bank_total_supply_query, register_delegator_delegations_query,
+    register_delegator_rewards_query, register_delegator_unbonding_delegations_query,
+    register_denom_metadata_query, register_denom_traces_query, register_fee_allowance_query,
+    register_gamm_pool_query, register_gov_proposals_query, register_gov_votes_query,
+    register_redelegations_query, register_transfers_query, register_validator_signing_info_query,
+    register_validators_info_query, register_wasm_contract_state_query, remove_interchain_query,
+    update_interchain_query,
 };
 use neutron_sdk::sudo::msg::SudoMsg;
 use neutron_sdk::{NeutronError, NeutronResult};
 
 use crate::integration_tests_mock_handlers::{set_kv_query_mock, unset_kv_query_mock};
+use neutron_sdk::interchain_queries::helpers::create_cw_storage_map_key;
 use neutron_sdk::interchain_queries::types::{
     TransactionFilterItem, TransactionFilterOp, TransactionFilterValue,
     COSMOS_SDK_TRANSFER_MSG_URL, RECIPIENT_FIELD,
@@ -85,6 +98,53 @@ pub fn execute(
             denom,
             update_period,
         ),
+        ExecuteMsg::RegisterBankTotalSupplyQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            denoms,
+        } => register_bank_total_supply_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            denoms,
+            update_period,
+        ),
+        ExecuteMsg::RegisterDenomMetadataQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            denoms,
+        } => {
+            register_denom_metadata_query(deps, env, connection_id, zone_id, denoms, update_period)
+        }
+        ExecuteMsg::RegisterValidatorsInfoQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            validators,
+        } => register_validators_info_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            validators,
+            update_period,
+        ),
+        ExecuteMsg::RegisterValidatorsSigningInfosQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            validators,
+        } => register_validator_signing_info_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            validators,
+            update_period,
+        ),
         ExecuteMsg::RegisterDelegatorDelegationsQuery {
             zone_id,
             connection_id,
@@ -100,6 +160,147 @@ pub fn execute(
             validators,
             update_period,
         ),
+        ExecuteMsg::RegisterDelegatorUnbondingDelegationsQuery {
+            zone_id,
+            connection_id,
+            delegator,
+            validators,
+            update_period,
+        } => register_delegator_unbonding_delegations_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            delegator,
+            validators,
+            update_period,
+        ),
+        ExecuteMsg::RegisterRedelegationsQuery {
+            zone_id,
+            connection_id,
+            delegator,
+            validators_pairs,
+            update_period,
+        } => register_redelegations_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            delegator,
+            validators_pairs,
+            update_period,
+        ),
+        ExecuteMsg::RegisterDelegatorRewardsQuery {
+            zone_id,
+            connection_id,
+            delegator,
+            validators,
+            slash_events,
+            update_period,
+        } => register_delegator_rewards_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            delegator,
+            validators,
+            slash_events,
+            update_period,
+        ),
+        ExecuteMsg::RegisterGovernmentProposalsQuery {
+            zone_id,
+            connection_id,
+            proposals_ids,
+            update_period,
+        } => register_gov_proposals_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            proposals_ids,
+            update_period,
+        ),
+        ExecuteMsg::RegisterGovernmentProposalVotesQuery {
+            zone_id,
+            connection_id,
+            proposal_id,
+            voters,
+            update_period,
+        } => register_gov_votes_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            proposal_id,
+            voters,
+            update_period,
+        ),
+        ExecuteMsg::RegisterAccountQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            addr,
+        } => register_account_query(deps, env, connection_id, zone_id, addr, update_period),
+        ExecuteMsg::RegisterDenomTracesQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            hashes,
+        } => register_denom_traces_query(deps, env, connection_id, zone_id, hashes, update_period),
+        ExecuteMsg::RegisterAuthzGrantsQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            granter,
+            grantee,
+            msg_type_urls,
+        } => register_authz_grants_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            granter,
+            grantee,
+            msg_type_urls,
+            update_period,
+        ),
+        ExecuteMsg::RegisterFeeAllowanceQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            granter,
+            grantee,
+        } => register_fee_allowance_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            granter,
+            grantee,
+            update_period,
+        ),
+        ExecuteMsg::RegisterGammPoolQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            pool_id,
+        } => register_gamm_pool_query(deps, env, connection_id, zone_id, pool_id, update_period),
+        ExecuteMsg::RegisterCw20BalanceQuery {
+            zone_id,
+            connection_id,
+            update_period,
+            cw20_contract_address,
+            account_address,
+        } => register_wasm_contract_state_query(
+            deps,
+            env,
+            connection_id,
+            zone_id,
+            cw20_contract_address,
+            // balances are stored in the cw20 contract's Map with "balance" namespace
+            create_cw_storage_map_key("balance", &[account_address])?,
+            update_period,
+        ),
         ExecuteMsg::RegisterTransfersQuery {
             zone_id,
             connection_id,
@@ -129,16 +330,66 @@ pub fn execute(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps<InterchainQueries>, env: Env, msg: QueryMsg) -> NeutronResult<Binary> {
     match msg {
-        //TODO: check if query.result.height is too old (for all interchain queries)
         QueryMsg::Balance { query_id } => query_balance(deps, env, query_id),
+        QueryMsg::BankTotalSupply { query_id } => query_bank_total(deps, env, query_id),
+        QueryMsg::DenomMetadata { query_id } => query_denom_metadata(deps, env, query_id),
         QueryMsg::GetDelegations { query_id } => query_delegations(deps, env, query_id),
+        QueryMsg::ValidatorsInfo { query_id } => query_validators_info(deps, env, query_id),
+        QueryMsg::ValidatorsSigningInfos { query_id } => {
+            query_validators_signing_infos(deps, env, query_id)
+        }
+        QueryMsg::GetUnbondingDelegations { query_id } => {
+            query_unbonding_delegations(deps, env, query_id)
+        }
+        QueryMsg::GetRedelegations { query_id } => query_redelegations(deps, env, query_id),
+        QueryMsg::GetDelegatorRewards { query_id } => query_delegator_rewards(deps, env, query_id),
+        QueryMsg::GovernmentProposals { query_id } => {
+            query_government_proposals(deps, env, query_id)
+        }
+        QueryMsg::GovernmentProposalVotes { query_id } => {
+            query_government_proposal_votes(deps, env, query_id)
+        }
+        QueryMsg::Account { query_id } => query_account(deps, env, query_id),
+        QueryMsg::Cw20Balance { query_id } => {
+            query_wasm_contract_state::<Uint128>(deps, env, query_id)
+        }
+        QueryMsg::GammPool { query_id } => query_gamm_pool(deps, env, query_id),
+        QueryMsg::DenomTraces { query_id } => query_denom_traces(deps, env, query_id),
+        QueryMsg::AuthzGrants { query_id } => query_authz_grants(deps, env, query_id),
+        QueryMsg::FeeAllowance { query_id } => query_fee_allowance(deps, env, query_id),
+        QueryMsg::FreshBalance {
+            query_id,
+            freshness,
+        } => query_fresh_balance(deps, env, query_id, freshness),
+        QueryMsg::FreshDelegations {
+            query_id,
+            freshness,
+        } => query_fresh_delegations(deps, env, query_id, freshness),
         QueryMsg::GetRegisteredQuery { query_id } => query_registered_query(deps, query_id),
+        QueryMsg::GetRegisteredQueries { pagination } => {
+            query_contract_registered_queries(deps, env, pagination)
+        }
         QueryMsg::GetRecipientTxs { recipient } => query_recipient_txs(deps, recipient),
         QueryMsg::GetTransfersAmount {} => query_transfers_amount(deps),
         QueryMsg::KvCallbackStats { query_id } => query_kv_callback_stats(deps, query_id),
     }
 }
 
+/// Returns interchain queries registered by the contract
+fn query_contract_registered_queries(
+    deps: Deps<InterchainQueries>,
+    env: Env,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<Binary> {
+    let filter = RegisteredQueriesFilter {
+        owner: Some(env.contract.address.to_string()),
+        ..Default::default()
+    };
+    Ok(to_binary(&get_registered_queries(
+        deps, filter, pagination,
+    )?)?)
+}
+
 fn query_recipient_txs(deps: Deps<InterchainQueries>, recipient: String) -> NeutronResult<Binary> {
     let txs = RECIPIENT_TXS
         .load(deps.storage, &recipient)
@@ -1,5 +1,7 @@
 use crate::state::Transfer;
-use neutron_sdk::bindings::types::KVKey;
+use neutron_sdk::bindings::types::{KVKey, PageRequest};
+use neutron_sdk::interchain_queries::queries::FreshnessPolicy;
+use neutron_sdk::interchain_queries::types::ValidatorSlashEventKey;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -16,12 +18,37 @@ pub enum ExecuteMsg {
         addr: String,
         denom: String,
     },
+    RegisterBankTotalSupplyQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        denoms: Vec<String>,
+    },
+    RegisterDenomMetadataQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        denoms: Vec<String>,
+    },
+    RegisterValidatorsInfoQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        validators: Vec<String>,
+    },
+    RegisterValidatorsSigningInfosQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        /// consensus addresses of validators
+        validators: Vec<String>,
+    },
     RegisterTransfersQuery {
         zone_id: String,
         connection_id: String,
         update_period: u64,
         recipient: String,
-        min_height: Option<u128>,
+        min_height: Option<u64>,
     },
     RegisterDelegatorDelegationsQuery {
         delegator: String,
@@ -30,6 +57,85 @@ pub enum ExecuteMsg {
         connection_id: String,
         update_period: u64,
     },
+    RegisterDelegatorUnbondingDelegationsQuery {
+        delegator: String,
+        validators: Vec<String>,
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+    },
+    RegisterRedelegationsQuery {
+        delegator: String,
+        /// (source validator, destination validator) pairs
+        validators_pairs: Vec<(String, String)>,
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+    },
+    RegisterDelegatorRewardsQuery {
+        delegator: String,
+        validators: Vec<String>,
+        slash_events: Vec<ValidatorSlashEventKey>,
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+    },
+    RegisterGovernmentProposalsQuery {
+        zone_id: String,
+        connection_id: String,
+        proposals_ids: Vec<u64>,
+        update_period: u64,
+    },
+    RegisterGovernmentProposalVotesQuery {
+        zone_id: String,
+        connection_id: String,
+        proposal_id: u64,
+        voters: Vec<String>,
+        update_period: u64,
+    },
+    RegisterAccountQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        addr: String,
+    },
+    RegisterDenomTracesQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        /// IBC denoms (ibc/{hash}) or their hashes
+        hashes: Vec<String>,
+    },
+    RegisterAuthzGrantsQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        granter: String,
+        grantee: String,
+        msg_type_urls: Vec<String>,
+    },
+    RegisterFeeAllowanceQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        granter: String,
+        grantee: String,
+    },
+    RegisterGammPoolQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        pool_id: u64,
+    },
+    RegisterCw20BalanceQuery {
+        zone_id: String,
+        connection_id: String,
+        update_period: u64,
+        /// address of a cw20 contract on remote chain
+        cw20_contract_address: String,
+        /// address of an account which balance of cw20 token you want to get
+        account_address: String,
+    },
     UpdateInterchainQuery {
         query_id: u64,
         new_keys: Option<Vec<KVKey>>,
@@ -51,11 +157,80 @@ pub enum ExecuteMsg {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    Balance { query_id: u64 },
-    GetDelegations { query_id: u64 },
-    GetRegisteredQuery { query_id: u64 },
-    GetRecipientTxs { recipient: String },
-    KvCallbackStats { query_id: u64 },
+    Balance {
+        query_id: u64,
+    },
+    BankTotalSupply {
+        query_id: u64,
+    },
+    DenomMetadata {
+        query_id: u64,
+    },
+    GetDelegations {
+        query_id: u64,
+    },
+    ValidatorsInfo {
+        query_id: u64,
+    },
+    ValidatorsSigningInfos {
+        query_id: u64,
+    },
+    GetUnbondingDelegations {
+        query_id: u64,
+    },
+    GetRedelegations {
+        query_id: u64,
+    },
+    GetDelegatorRewards {
+        query_id: u64,
+    },
+    GovernmentProposals {
+        query_id: u64,
+    },
+    GovernmentProposalVotes {
+        query_id: u64,
+    },
+    Account {
+        query_id: u64,
+    },
+    Cw20Balance {
+        query_id: u64,
+    },
+    GammPool {
+        query_id: u64,
+    },
+    DenomTraces {
+        query_id: u64,
+    },
+    AuthzGrants {
+        query_id: u64,
+    },
+    FeeAllowance {
+        query_id: u64,
+    },
+    /// Returns balance if the query result is fresh enough by the **freshness** policy
+    FreshBalance {
+        query_id: u64,
+        freshness: FreshnessPolicy,
+    },
+    /// Returns delegations if the query result is fresh enough by the **freshness** policy
+    FreshDelegations {
+        query_id: u64,
+        freshness: FreshnessPolicy,
+    },
+    GetRegisteredQuery {
+        query_id: u64,
+    },
+    /// Returns interchain queries registered by the contract
+    GetRegisteredQueries {
+        pagination: Option<PageRequest>,
+    },
+    GetRecipientTxs {
+        recipient: String,
+    },
+    KvCallbackStats {
+        query_id: u64,
+    },
     GetTransfersAmount {},
 }
 
@@ -14,11 +14,16 @@
 
 use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_slice, Binary, Coin, ContractResult, CustomQuery, FullDelegation, OwnedDeps, Querier,
-    QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, Validator,
+    from_binary, from_slice, to_binary, Binary, Coin, ContractResult, CustomQuery, FullDelegation,
+    OwnedDeps, Querier, QuerierResult, QueryRequest, SystemError, SystemResult, Uint128, Validator,
 };
 
-use neutron_sdk::bindings::query::InterchainQueries;
+use neutron_sdk::bindings::query::{
+    InterchainQueries, InterchainQueriesParams, InterchainTxsParams,
+    QueryInterchainQueriesParamsResponse, QueryInterchainTxsParamsResponse,
+    QueryRegisteredQueriesResponse, QueryRegisteredQueryResponse,
+};
+use neutron_sdk::bindings::types::{PageRequest, PageResponse, RegisteredQuery};
 use schemars::JsonSchema;
 
 use serde::{Deserialize, Serialize};
@@ -54,6 +59,7 @@ pub struct WasmMockQuerier {
     base: MockQuerier<InterchainQueries>,
     query_reponses: HashMap<u64, Binary>,
     registred_queries: HashMap<u64, Binary>,
+    interchain_queries_params: InterchainQueriesParams,
 }
 
 impl Querier for WasmMockQuerier {
@@ -84,16 +90,89 @@ impl WasmMockQuerier {
                     (*self.registred_queries.get(query_id).unwrap()).clone(),
                 ))
             }
-            QueryRequest::Custom(InterchainQueries::RegisteredInterchainQueries {}) => {
-                todo!()
+            QueryRequest::Custom(InterchainQueries::RegisteredInterchainQueries {
+                owner,
+                connection_id,
+                query_type,
+                pagination,
+            }) => {
+                let response =
+                    self.registered_queries_response(owner, connection_id, query_type, pagination);
+                SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
             }
             QueryRequest::Custom(InterchainQueries::InterchainAccountAddress { .. }) => {
                 todo!()
             }
+            QueryRequest::Custom(InterchainQueries::InterchainQueriesParams {}) => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&QueryInterchainQueriesParamsResponse {
+                        params: self.interchain_queries_params.clone(),
+                    })
+                    .unwrap(),
+                ))
+            }
+            QueryRequest::Custom(InterchainQueries::InterchainTxsParams {}) => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&QueryInterchainTxsParamsResponse {
+                        params: InterchainTxsParams {
+                            msg_submit_tx_max_messages: 16,
+                        },
+                    })
+                    .unwrap(),
+                ))
+            }
             _ => self.base.handle_query(request),
         }
     }
 
+    fn registered_queries_response(
+        &self,
+        owner: &Option<String>,
+        connection_id: &Option<String>,
+        query_type: &Option<String>,
+        pagination: &Option<PageRequest>,
+    ) -> QueryRegisteredQueriesResponse {
+        let mut registered_queries: Vec<RegisteredQuery> = self
+            .registred_queries
+            .values()
+            .map(|q| {
+                from_binary::<QueryRegisteredQueryResponse>(q)
+                    .unwrap()
+                    .registered_query
+            })
+            .filter(|q| owner.as_ref().map_or(true, |o| *o == q.owner))
+            .filter(|q| {
+                connection_id
+                    .as_ref()
+                    .map_or(true, |c| *c == q.connection_id)
+            })
+            .filter(|q| query_type.as_ref().map_or(true, |t| *t == q.query_type))
+            .collect();
+        registered_queries.sort_by_key(|q| q.id);
+
+        let pagination = pagination.clone().unwrap_or_default();
+        if pagination.reverse {
+            registered_queries.reverse();
+        }
+        let total = registered_queries.len() as u64;
+        let limit = match pagination.limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+
+        QueryRegisteredQueriesResponse {
+            registered_queries: registered_queries
+                .into_iter()
+                .skip(pagination.offset as usize)
+                .take(limit)
+                .collect(),
+            pagination: Some(PageResponse {
+                next_key: None,
+                total: if pagination.count_total { total } else { 0 },
+            }),
+        }
+    }
+
     pub fn _update_staking(
         &mut self,
         denom: &str,
@@ -109,6 +188,9 @@ impl WasmMockQuerier {
     pub fn add_registred_queries(&mut self, query_id: u64, response: Binary) {
         self.registred_queries.insert(query_id, response);
     }
+    pub fn set_interchain_queries_params(&mut self, params: InterchainQueriesParams) {
+        self.interchain_queries_params = params;
+    }
 }
 
 #[derive(Clone, Default)]
@@ -127,6 +209,12 @@ impl WasmMockQuerier {
             base,
             query_reponses: HashMap::new(),
             registred_queries: HashMap::new(),
+            interchain_queries_params: InterchainQueriesParams {
+                query_submit_timeout: 1036800,
+                query_deposit: vec![],
+                max_kv_query_keys_count: 32,
+                max_transactions_filters: 32,
+            },
         }
     }
 }
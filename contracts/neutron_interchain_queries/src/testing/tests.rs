@@ -21,10 +21,11 @@ use cosmwasm_std::{
     from_binary, to_binary, Addr, Binary, Coin, Delegation, Env, MessageInfo, OwnedDeps, StdError,
 };
 use neutron_sdk::bindings::query::{
-    InterchainQueries, QueryRegisteredQueryResponse, QueryRegisteredQueryResultResponse,
+    InterchainQueries, InterchainQueriesParams, QueryRegisteredQueriesResponse,
+    QueryRegisteredQueryResponse, QueryRegisteredQueryResultResponse,
 };
 use neutron_sdk::bindings::types::{
-    decode_hex, InterchainQueryResult, KVKey, KVKeys, RegisteredQuery, StorageValue,
+    decode_hex, InterchainQueryResult, KVKey, KVKeys, PageRequest, RegisteredQuery, StorageValue,
 };
 use neutron_sdk::interchain_queries::helpers::{
     create_account_denom_balance_key, decode_and_convert,
@@ -36,7 +37,7 @@ use neutron_sdk::interchain_queries::types::{
 use prost::Message as ProstMessage;
 
 use neutron_sdk::interchain_queries::queries::{
-    DelegatorDelegationsResponse, QueryBalanceResponse,
+    DelegatorDelegationsResponse, FreshnessPolicy, QueryBalanceResponse,
 };
 use neutron_sdk::NeutronError;
 use schemars::_serde_json::to_string;
@@ -85,7 +86,7 @@ fn build_interchain_query_balance_response(addr: Addr, denom: String, amount: St
     let balance_amount = CosmosCoin { denom, amount };
 
     let s = StorageValue {
-        storage_prefix: "".to_string(),
+        storage_prefix: "bank".to_string(),
         key: Binary(balance_key),
         value: Binary(balance_amount.encode_to_vec()),
     };
@@ -159,6 +160,88 @@ fn test_query_balance() {
     )
 }
 
+#[test]
+fn test_query_fresh_balance() {
+    let mut deps = dependencies(&[]);
+
+    let msg = ExecuteMsg::RegisterBalanceQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        denom: "uosmo".to_string(),
+    };
+
+    let keys = register_query(&mut deps, mock_env(), mock_info("", &[]), msg);
+
+    let env = mock_env();
+    let registered_query = build_registered_query_response(
+        1,
+        QueryParam::Keys(keys.0),
+        QueryType::KV.into(),
+        env.block.height - 5,
+    );
+
+    deps.querier.add_registred_queries(1, registered_query);
+    deps.querier.add_query_response(
+        1,
+        build_interchain_query_balance_response(
+            Addr::unchecked("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"),
+            "uosmo".to_string(),
+            "8278104".to_string(),
+        ),
+    );
+
+    let query_balance = QueryMsg::FreshBalance {
+        query_id: 1,
+        freshness: FreshnessPolicy {
+            max_local_age: Some(10),
+            min_remote_height: None,
+        },
+    };
+    let resp: QueryBalanceResponse =
+        from_binary(&query(deps.as_ref(), env.clone(), query_balance).unwrap()).unwrap();
+    assert_eq!(
+        resp,
+        QueryBalanceResponse {
+            last_submitted_local_height: env.block.height - 5,
+            balances: Balances {
+                coins: vec![Coin::new(8278104u128, "uosmo")]
+            }
+        }
+    );
+
+    // the result was submitted too many blocks ago
+    let query_balance = QueryMsg::FreshBalance {
+        query_id: 1,
+        freshness: FreshnessPolicy {
+            max_local_age: Some(3),
+            min_remote_height: None,
+        },
+    };
+    assert_eq!(
+        query(deps.as_ref(), env.clone(), query_balance).unwrap_err(),
+        NeutronError::StaleQueryResult {
+            query_id: 1,
+            local_height: env.block.height - 5,
+            remote_height: 0,
+        }
+    );
+
+    // the result was taken at too low remote height
+    let query_balance = QueryMsg::FreshBalance {
+        query_id: 1,
+        freshness: FreshnessPolicy {
+            max_local_age: None,
+            min_remote_height: Some(1),
+        },
+    };
+    assert!(matches!(
+        query(deps.as_ref(), env, query_balance),
+        Err(NeutronError::StaleQueryResult { .. })
+    ));
+}
+
 #[test]
 fn test_query_delegator_delegations() {
     let mut deps = dependencies(&[]);
@@ -473,3 +556,172 @@ fn test_sudo_tx_query_result_min_height_callback() {
         ])
     );
 }
+
+#[test]
+fn test_query_contract_registered_queries() {
+    let mut deps = dependencies(&[]);
+    let env = mock_env();
+
+    let owners = [
+        env.contract.address.to_string(),
+        "neutron1anotherowner".to_string(),
+        env.contract.address.to_string(),
+        env.contract.address.to_string(),
+    ];
+    for (i, owner) in owners.iter().enumerate() {
+        let id = i as u64 + 1;
+        let mut registered_query: QueryRegisteredQueryResponse = from_binary(
+            &build_registered_query_response(id, QueryParam::Keys(vec![]), QueryType::KV.into(), 0),
+        )
+        .unwrap();
+        registered_query.registered_query.owner = owner.clone();
+        deps.querier
+            .add_registred_queries(id, to_binary(&registered_query).unwrap());
+    }
+
+    let resp: QueryRegisteredQueriesResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRegisteredQueries { pagination: None },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let ids: Vec<u64> = resp.registered_queries.iter().map(|q| q.id).collect();
+    assert_eq!(ids, vec![1, 3, 4]);
+
+    let resp: QueryRegisteredQueriesResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            env,
+            QueryMsg::GetRegisteredQueries {
+                pagination: Some(PageRequest {
+                    offset: 1,
+                    limit: 1,
+                    count_total: true,
+                    ..Default::default()
+                }),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let ids: Vec<u64> = resp.registered_queries.iter().map(|q| q.id).collect();
+    assert_eq!(ids, vec![3]);
+    assert_eq!(resp.pagination.unwrap().total, 3);
+}
+
+#[test]
+fn test_register_query_deposit() {
+    let mut deps = dependencies(&[Coin::new(500_000u128, "untrn")]);
+    deps.querier
+        .set_interchain_queries_params(InterchainQueriesParams {
+            query_submit_timeout: 1036800,
+            query_deposit: vec![Coin::new(1_000_000u128, "untrn")],
+            max_kv_query_keys_count: 2,
+            max_transactions_filters: 32,
+        });
+
+    let msg = ExecuteMsg::RegisterBalanceQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        denom: "uosmo".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        NeutronError::InsufficientQueryDeposit {
+            required: Coin::new(1_000_000u128, "untrn"),
+            actual: Coin::new(500_000u128, "untrn"),
+        }
+    );
+
+    let msg_delegations = ExecuteMsg::RegisterDelegatorDelegationsQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        delegator: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        validators: vec!["osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string()],
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("", &[]),
+        msg_delegations,
+    )
+    .unwrap_err();
+    assert_eq!(err, NeutronError::TooManyKVQueryKeys { max: 2, actual: 3 });
+
+    let msg_transfers = ExecuteMsg::RegisterTransfersQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        recipient: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        min_height: None,
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg_transfers).unwrap_err();
+    assert_eq!(
+        err,
+        NeutronError::InsufficientQueryDeposit {
+            required: Coin::new(1_000_000u128, "untrn"),
+            actual: Coin::new(500_000u128, "untrn"),
+        }
+    );
+
+    let mut deps = dependencies(&[Coin::new(1_000_000u128, "untrn")]);
+    deps.querier
+        .set_interchain_queries_params(InterchainQueriesParams {
+            query_submit_timeout: 1036800,
+            query_deposit: vec![Coin::new(1_000_000u128, "untrn")],
+            max_kv_query_keys_count: 2,
+            max_transactions_filters: 32,
+        });
+    execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg).unwrap();
+}
+
+#[test]
+fn test_register_query_validation() {
+    let mut deps = dependencies(&[]);
+    deps.querier
+        .set_interchain_queries_params(InterchainQueriesParams {
+            query_submit_timeout: 1036800,
+            query_deposit: vec![],
+            max_kv_query_keys_count: 2,
+            max_transactions_filters: 1,
+        });
+
+    let msg = ExecuteMsg::RegisterBalanceQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 0,
+        addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        denom: "uosmo".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg).unwrap_err();
+    assert_eq!(err, NeutronError::ZeroUpdatePeriod {});
+
+    let msg = ExecuteMsg::RegisterDenomTracesQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        hashes: vec![],
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg).unwrap_err();
+    assert_eq!(err, NeutronError::EmptyKVKeys {});
+
+    let msg = ExecuteMsg::RegisterTransfersQuery {
+        zone_id: "zone".to_string(),
+        connection_id: "connection".to_string(),
+        update_period: 10,
+        recipient: "neutron1fj6yqrkpw6fmp7f7jhj57dujfpwal4m25dafzx".to_string(),
+        min_height: Some(100000),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info("", &[]), msg).unwrap_err();
+    assert_eq!(
+        err,
+        NeutronError::TooManyTransactionFilters { max: 1, actual: 2 }
+    );
+}
@@ -14,9 +14,7 @@
 
 use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
 use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
-use cosmos_sdk_proto::cosmos::staking::v1beta1::{
-    MsgDelegate, MsgDelegateResponse, MsgUndelegate, MsgUndelegateResponse,
-};
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgDelegate, MsgUndelegate};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
@@ -25,17 +23,17 @@ use cosmwasm_std::{
 };
 use osmosis_std::types::osmosis::gamm::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute};
 use osmosis_std::types::cosmos::base::v1beta1::{Coin as OsmoCoin};
-use prost::Message;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_sdk::bindings::query::{InterchainQueries, QueryInterchainAccountAddressResponse};
-use neutron_sdk::bindings::types::ProtobufAny;
+use neutron_sdk::bindings::types::ToProtobufAny;
 use neutron_sdk::interchain_txs::helpers::{
-    get_port_id, parse_item, parse_response, parse_sequence,
+    check_submit_tx_msgs_count, get_port_id, parse_response, parse_sequence,
 };
+use neutron_sdk::interchain_txs::types::IcaMsgResponse;
 use neutron_sdk::sudo::msg::{RequestPacket, SudoMsg};
 use neutron_sdk::NeutronResult;
 
@@ -71,11 +69,11 @@ pub fn instantiate(
 
 #[entry_point]
 pub fn execute(
-    deps: DepsMut,
+    deps: DepsMut<InterchainQueries>,
     env: Env,
     _: MessageInfo,
     msg: ExecuteMsg,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     deps.api
         .debug(format!("WASMDEBUG: execute: received msg: {:?}", msg).as_str());
     match msg {
@@ -103,12 +101,15 @@ pub fn execute(
             interchain_account_id,
             token_in,
             token_in_amount,
-        } => execute_swap(sender_on_dest_chain,
+            token_out_min_amount,
+        } => execute_swap(deps.as_ref(),
+                          sender_on_dest_chain,
                           routes,
                           connection_id,
                           interchain_account_id,
                           token_in,
-                          token_in_amount),
+                          token_in_amount,
+                          token_out_min_amount),
         ExecuteMsg::Send {
             sender_on_dest_chain,
             receiver_on_dest_chain,
@@ -116,7 +117,8 @@ pub fn execute(
             token_amount,
             connection_id,
             interchain_account_id
-        } => execute_send(sender_on_dest_chain,
+        } => execute_send(deps.as_ref(),
+                          sender_on_dest_chain,
                           receiver_on_dest_chain,
                           token_denom,
                           token_amount,
@@ -128,7 +130,8 @@ pub fn execute(
             validator,
             amount,
             token_denom, connection_id
-        } => execute_custom_delegate(interchain_account_id,
+        } => execute_custom_delegate(deps.as_ref(),
+                                     interchain_account_id,
                                      sender_on_dest_chain,
                                      validator,
                                      amount,
@@ -188,7 +191,7 @@ pub fn query_acknowledgement_result(
 }
 
 fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
-    deps: DepsMut,
+    deps: DepsMut<InterchainQueries>,
     msg: C,
     payload: SudoPayload,
 ) -> StdResult<SubMsg<T>> {
@@ -197,11 +200,11 @@ fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
 }
 
 fn execute_register_ica(
-    deps: DepsMut,
+    deps: DepsMut<InterchainQueries>,
     env: Env,
     connection_id: String,
     interchain_account_id: String,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     let register =
         NeutronMsg::register_interchain_account(connection_id, interchain_account_id.clone());
     let key = get_port_id(env.contract.address.as_str(), &interchain_account_id);
@@ -210,13 +213,13 @@ fn execute_register_ica(
 }
 
 fn execute_delegate(
-    mut deps: DepsMut,
+    mut deps: DepsMut<InterchainQueries>,
     env: Env,
     interchain_account_id: String,
     validator: String,
     amount: u128,
     timeout: Option<u64>,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     let (delegator, connection_id) = get_ica(deps.as_ref(), &env, &interchain_account_id)?;
     let delegate_msg = MsgDelegate {
         delegator_address: delegator,
@@ -226,22 +229,15 @@ fn execute_delegate(
             amount: amount.to_string(),
         }),
     };
-    let mut buf = Vec::new();
-    buf.reserve(delegate_msg.encoded_len());
-
-    if let Err(e) = delegate_msg.encode(&mut buf) {
-        return Err(StdError::generic_err(format!("Encode error: {}", e)));
-    }
+    let any_msg = delegate_msg.to_protobuf_any();
 
-    let any_msg = ProtobufAny {
-        type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
-        value: Binary::from(buf),
-    };
+    let msgs = vec![any_msg];
+    check_submit_tx_msgs_count(deps.as_ref(), msgs.len())?;
 
     let cosmos_msg = NeutronMsg::submit_tx(
         connection_id,
         interchain_account_id.clone(),
-        vec![any_msg],
+        msgs,
         "".to_string(),
         timeout.unwrap_or(DEFAULT_TIMEOUT_SECONDS),
     );
@@ -259,35 +255,31 @@ fn execute_delegate(
 }
 
 fn execute_swap(
+    deps: Deps<InterchainQueries>,
     sender_on_dest_chain: String,
     routes: Vec<SwapAmountInRoute>,
     connection_id: String,
     interchain_account_id: String,
     token_in: String,
     token_in_amount: String,
-) -> StdResult<Response<NeutronMsg>> {
+    token_out_min_amount: Option<String>,
+) -> NeutronResult<Response<NeutronMsg>> {
     let swap_message = MsgSwapExactAmountIn {
         sender: sender_on_dest_chain,
         routes,
         token_in: Some(OsmoCoin { denom: token_in.clone(), amount: token_in_amount.into() }),
-        token_out_min_amount: "1".to_string(),
+        token_out_min_amount: token_out_min_amount.unwrap_or_else(|| "1".to_string()),
     };
 
-    let mut buf = Vec::new();
-    buf.reserve(Message::encoded_len(&swap_message));
-    if let Err(e) = swap_message.encode(&mut buf) {
-        return Err(StdError::generic_err(format!("Encode error: {}", e)));
-    }
+    let cosmos_msg_swap = swap_message.to_protobuf_any();
 
-    let cosmos_msg_swap = ProtobufAny {
-        type_url: "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn".to_string(),
-        value: Binary::from(buf),
-    };
+    let msgs = vec![cosmos_msg_swap];
+    check_submit_tx_msgs_count(deps, msgs.len())?;
 
     let cosmos_msg = NeutronMsg::submit_tx(
         connection_id,
         interchain_account_id.clone(),
-        vec![cosmos_msg_swap],
+        msgs,
         "".to_string(),
         DEFAULT_TIMEOUT_SECONDS,
     );
@@ -299,34 +291,29 @@ fn execute_swap(
 
 
 fn execute_send(
+    deps: Deps<InterchainQueries>,
     sender_on_dest_chain: String,
     receiver_on_dest_chain: String,
     token_denom: String,
     token_amount: String,
     connection_id: String,
     interchain_account_id: String,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     let send_message = MsgSend {
         from_address: sender_on_dest_chain.to_string(),
         to_address: receiver_on_dest_chain.to_string(),
         amount: vec![Coin { denom: token_denom, amount: token_amount }],
     };
 
-    let mut buf = Vec::new();
-    buf.reserve(Message::encoded_len(&send_message));
-    if let Err(e) = send_message.encode(&mut buf) {
-        return Err(StdError::generic_err(format!("Encode error: {}", e)));
-    }
+    let cosmos_msg_send = send_message.to_protobuf_any();
 
-    let cosmos_msg_send = ProtobufAny {
-        type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
-        value: Binary::from(buf),
-    };
+    let msgs = vec![cosmos_msg_send];
+    check_submit_tx_msgs_count(deps, msgs.len())?;
 
     let cosmos_msg = NeutronMsg::submit_tx(
         connection_id,
         interchain_account_id.clone(),
-        vec![cosmos_msg_send],
+        msgs,
         "".to_string(),
         DEFAULT_TIMEOUT_SECONDS,
     );
@@ -337,13 +324,14 @@ fn execute_send(
 }
 
 fn execute_custom_delegate(
+    deps: Deps<InterchainQueries>,
     interchain_account_id: String,
     sender_on_dest_chain: String,
     validator: String,
     amount: u128,
     token_denom: String,
     connection_id: String,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     let delegate_msg = MsgDelegate {
         delegator_address: sender_on_dest_chain,
         validator_address: validator,
@@ -352,22 +340,15 @@ fn execute_custom_delegate(
             amount: amount.to_string(),
         }),
     };
-    let mut buf = Vec::new();
+    let cosmos_msg_delegate = delegate_msg.to_protobuf_any();
 
-    buf.reserve(Message::encoded_len(&delegate_msg));
-    if let Err(e) = delegate_msg.encode(&mut buf) {
-        return Err(StdError::generic_err(format!("Encode error: {}", e)));
-    }
-
-    let cosmos_msg_delegate = ProtobufAny {
-        type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
-        value: Binary::from(buf),
-    };
+    let msgs = vec![cosmos_msg_delegate];
+    check_submit_tx_msgs_count(deps, msgs.len())?;
 
     let cosmos_msg = NeutronMsg::submit_tx(
         connection_id,
         interchain_account_id.clone(),
-        vec![cosmos_msg_delegate],
+        msgs,
         "".to_string(),
         DEFAULT_TIMEOUT_SECONDS,
     );
@@ -378,13 +359,13 @@ fn execute_custom_delegate(
 }
 
 fn execute_undelegate(
-    mut deps: DepsMut,
+    mut deps: DepsMut<InterchainQueries>,
     env: Env,
     interchain_account_id: String,
     validator: String,
     amount: u128,
     timeout: Option<u64>,
-) -> StdResult<Response<NeutronMsg>> {
+) -> NeutronResult<Response<NeutronMsg>> {
     let (delegator, connection_id) = get_ica(deps.as_ref(), &env, &interchain_account_id)?;
     let delegate_msg = MsgUndelegate {
         delegator_address: delegator,
@@ -394,22 +375,15 @@ fn execute_undelegate(
             amount: amount.to_string(),
         }),
     };
-    let mut buf = Vec::new();
-    buf.reserve(delegate_msg.encoded_len());
+    let any_msg = delegate_msg.to_protobuf_any();
 
-    if let Err(e) = delegate_msg.encode(&mut buf) {
-        return Err(StdError::generic_err(format!("Encode error: {}", e)));
-    }
-
-    let any_msg = ProtobufAny {
-        type_url: "/cosmos.staking.v1beta1.MsgUndelegate".to_string(),
-        value: Binary::from(buf),
-    };
+    let msgs = vec![any_msg];
+    check_submit_tx_msgs_count(deps.as_ref(), msgs.len())?;
 
     let cosmos_msg = NeutronMsg::submit_tx(
         connection_id,
         interchain_account_id.clone(),
-        vec![any_msg],
+        msgs,
         "".to_string(),
         timeout.unwrap_or(DEFAULT_TIMEOUT_SECONDS),
     );
@@ -426,7 +400,9 @@ fn execute_undelegate(
     Ok(Response::default().add_submessages(vec![submsg]))
 }
 
-fn execute_clean_ack_results(deps: DepsMut) -> StdResult<Response<NeutronMsg>> {
+fn execute_clean_ack_results(
+    deps: DepsMut<InterchainQueries>,
+) -> NeutronResult<Response<NeutronMsg>> {
     let keys: Vec<StdResult<String>> = ACKNOWLEDGEMENT_RESULTS
         .keys(deps.storage, None, None, cosmwasm_std::Order::Descending)
         .collect();
@@ -437,7 +413,7 @@ fn execute_clean_ack_results(deps: DepsMut) -> StdResult<Response<NeutronMsg>> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> StdResult<Response> {
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> NeutronResult<Response> {
     match msg {
         SudoMsg::Response { request, data } => sudo_response(deps, request, data),
         SudoMsg::Error { request, details } => sudo_error(deps, request, details),
@@ -471,7 +447,7 @@ fn sudo_open_ack(
     _channel_id: String,
     _counterparty_channel_id: String,
     counterparty_version: String,
-) -> StdResult<Response> {
+) -> NeutronResult<Response> {
     let parsed_version: Result<OpenAckVersion, _> =
         serde_json_wasm::from_str(counterparty_version.as_str());
     if let Ok(parsed_version) = parsed_version {
@@ -485,10 +461,10 @@ fn sudo_open_ack(
         )?;
         return Ok(Response::default());
     }
-    Err(StdError::generic_err("Can't parse counterparty_version"))
+    Err(StdError::generic_err("Can't parse counterparty_version").into())
 }
 
-fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResult<Response> {
+fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> NeutronResult<Response> {
     deps.api.debug(
         format!(
             "WASMDEBUG: sudo_response: sudo received: {:?} {:?}",
@@ -511,21 +487,13 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
 
     let mut item_types = vec![];
     for item in parsed_data {
-        let item_type = item.msg_type.as_str();
-        item_types.push(item_type.to_string());
-        match item_type {
-            "/cosmos.staking.v1beta1.MsgUndelegate" => {
-                let out: MsgUndelegateResponse = parse_item(&item.data)?;
-                let completion_time = out
-                    .completion_time
-                    .ok_or_else(|| StdError::generic_err("failed to get completion time"))?;
+        item_types.push(item.msg_type.clone());
+        match IcaMsgResponse::decode(&item.msg_type, &item.data)? {
+            IcaMsgResponse::Undelegate { completion_time } => {
                 deps.api
                     .debug(format!("Undelegation completion time: {:?}", completion_time).as_str());
             }
-            "/cosmos.staking.v1beta1.MsgDelegate" => {
-                let _out: MsgDelegateResponse = parse_item(&item.data)?;
-            }
-            _ => {
+            IcaMsgResponse::Raw { .. } => {
                 deps.api.debug(
                     format!(
                         "This type of acknowledgement is not implemented: {:?}",
@@ -534,6 +502,10 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
                         .as_str(),
                 );
             }
+            response => {
+                deps.api
+                    .debug(format!("WASMDEBUG: sudo_response: response: {:?}", response).as_str());
+            }
         }
     }
 
@@ -546,7 +518,7 @@ fn sudo_response(deps: DepsMut, request: RequestPacket, data: Binary) -> StdResu
     Ok(Response::default())
 }
 
-fn sudo_timeout(deps: DepsMut, _env: Env, request: RequestPacket) -> StdResult<Response> {
+fn sudo_timeout(deps: DepsMut, _env: Env, request: RequestPacket) -> NeutronResult<Response> {
     deps.api
         .debug(format!("WASMDEBUG: sudo timeout request: {:?}", request).as_str());
 
@@ -567,7 +539,7 @@ fn sudo_timeout(deps: DepsMut, _env: Env, request: RequestPacket) -> StdResult<R
     Ok(Response::default())
 }
 
-fn sudo_error(deps: DepsMut, request: RequestPacket, details: String) -> StdResult<Response> {
+fn sudo_error(deps: DepsMut, request: RequestPacket, details: String) -> NeutronResult<Response> {
     deps.api
         .debug(format!("WASMDEBUG: sudo error: {}", details).as_str());
     let seq_id = request
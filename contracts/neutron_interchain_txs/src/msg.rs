@@ -53,6 +53,9 @@ pub enum ExecuteMsg {
         interchain_account_id: String,
         token_in: String,
         token_in_amount: String,
+        /// minimal amount of the out token to receive, e.g. calculated with **GammPool::min_out_amount**
+        /// from a result of the gamm pool interchain query. Defaults to 1 if not set
+        token_out_min_amount: Option<String>,
     },
     Send {
         sender_on_dest_chain: String,
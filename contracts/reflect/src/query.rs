@@ -1,4 +1,5 @@
 use cosmwasm_std::{Binary, CustomQuery, QueryRequest};
+use neutron_sdk::bindings::types::PageRequest;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -19,10 +20,17 @@ pub enum InterchainQueries {
         interchain_account_id: String,
         connection_id: String,
     },
-    RegisteredInterchainQueries {},
+    RegisteredInterchainQueries {
+        owner: Option<String>,
+        connection_id: Option<String>,
+        query_type: Option<String>,
+        pagination: Option<PageRequest>,
+    },
     RegisteredInterchainQuery {
         query_id: u64,
     },
+    InterchainQueriesParams {},
+    InterchainTxsParams {},
 }
 
 impl CustomQuery for InterchainQueries {}
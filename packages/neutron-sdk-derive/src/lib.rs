@@ -0,0 +1,380 @@
+// Copyright 2022 Neutron Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parenthesized, parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Field, Fields,
+    GenericArgument, Ident, LitStr, PathArguments, Result, Token, Type,
+};
+
+/// Derives **KVReconstruct** for a struct with named fields.
+/// Every field is read from one storage value, the values go in the order of the fields.
+/// Fields are described with **kv** attribute:
+/// * **path** is a name of the module store the value is read from, e.g. `#[kv(path = "bank")]`;
+/// * **key** is an expression which builds the key of the value, e.g. `#[kv(key = create_total_denom_key(denom))]`;
+/// * **protobuf** (default), **json** or **raw** is a decoder of the value, **raw** fields are **Binary**.
+///
+/// If the field type is **Option**, an empty value is reconstructed as **None**.
+///
+/// Key expressions can use the params listed in the struct attribute `#[kv(keys(name: Type, ...))]`,
+/// the params are available in the expressions as references and the expressions can use `?`.
+/// Along with the implementation, the macro generates **{Struct}Keys** struct with the params.
+/// Its **into_kv_keys** method returns **KVKey**'s to register the query with,
+/// and its **reconstruct** method checks that the storage values are read by exactly these keys
+/// before reconstructing the struct, so the keys and the reconstruct logic are built from the same expressions.
+#[proc_macro_derive(KVReconstruct, attributes(kv))]
+pub fn derive_kv_reconstruct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_kv_reconstruct(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Decoder of a storage value
+enum Decoder {
+    Protobuf,
+    Json,
+    Raw,
+}
+
+/// Field of a struct with parsed **kv** attribute
+struct KVField<'a> {
+    field: &'a Field,
+    path: LitStr,
+    key: Expr,
+    decoder: Decoder,
+}
+
+/// Param of the keys struct, `name: Type`
+struct KeyParam {
+    ident: Ident,
+    ty: Type,
+}
+
+impl Parse for KeyParam {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+
+        Ok(KeyParam { ident, ty })
+    }
+}
+
+/// Single argument of the field **kv** attribute
+enum FieldArg {
+    Path(LitStr),
+    Key(Expr),
+    Decoder(Decoder),
+}
+
+impl Parse for FieldArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        if ident == "path" {
+            input.parse::<Token![=]>()?;
+            Ok(FieldArg::Path(input.parse()?))
+        } else if ident == "key" {
+            input.parse::<Token![=]>()?;
+            Ok(FieldArg::Key(input.parse()?))
+        } else if ident == "protobuf" {
+            Ok(FieldArg::Decoder(Decoder::Protobuf))
+        } else if ident == "json" {
+            Ok(FieldArg::Decoder(Decoder::Json))
+        } else if ident == "raw" {
+            Ok(FieldArg::Decoder(Decoder::Raw))
+        } else {
+            Err(Error::new(
+                ident.span(),
+                "unknown kv attribute, expected path, key, protobuf, json or raw",
+            ))
+        }
+    }
+}
+
+fn expand_kv_reconstruct(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "KVReconstruct can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "KVReconstruct can only be derived for structs",
+            ))
+        }
+    };
+
+    let key_params = parse_key_params(&input.attrs)?;
+    let kv_fields: Vec<KVField> = fields.iter().map(parse_kv_field).collect::<Result<_>>()?;
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let keys_ident = format_ident!("{}Keys", ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let values_count = kv_fields.len();
+
+    let params_fields = key_params.iter().map(|param| {
+        let name = &param.ident;
+        let ty = &param.ty;
+        quote! {
+            pub #name: #ty
+        }
+    });
+    let params_names = key_params.iter().map(|param| &param.ident);
+
+    let kv_keys = kv_fields.iter().map(|kv_field| {
+        let path = &kv_field.path;
+        let key = &kv_field.key;
+        quote! {
+            ::neutron_sdk::bindings::types::KVKey {
+                path: ::std::string::String::from(#path),
+                key: ::std::convert::Into::into(#key),
+            }
+        }
+    });
+
+    let reconstruct_fields = kv_fields.iter().enumerate().map(|(i, kv_field)| {
+        let name = &kv_field.field.ident;
+        let path = &kv_field.path;
+        let value = decode_value(kv_field);
+        quote! {
+            #name: {
+                let kv = &storage_values[#i];
+                ::neutron_sdk::interchain_queries::helpers::check_storage_prefix(kv, #path)?;
+                #value
+            }
+        }
+    });
+
+    let keys_doc = format!(
+        "Params of the keys of the storage values **{}** is reconstructed from",
+        ident
+    );
+    let reconstruct_doc = format!(
+        "Reconstructs **{}** from the storage values read by the keys built from these params",
+        ident
+    );
+
+    Ok(quote! {
+        #[doc = #keys_doc]
+        #vis struct #keys_ident {
+            #(#params_fields,)*
+        }
+
+        impl #keys_ident {
+            /// Returns **KVKey**'s built from the params
+            // key expressions don't have to use all the params
+            #[allow(unused_variables)]
+            pub fn kv_keys(
+                &self,
+            ) -> ::neutron_sdk::NeutronResult<::std::vec::Vec<::neutron_sdk::bindings::types::KVKey>> {
+                let Self { #(#params_names),* } = self;
+
+                ::std::result::Result::Ok(::std::vec![#(#kv_keys),*])
+            }
+
+            /// Returns **KVKey**'s to register the Interchain Query with
+            pub fn into_kv_keys(
+                self,
+            ) -> ::neutron_sdk::NeutronResult<::std::vec::Vec<::neutron_sdk::bindings::types::KVKey>> {
+                self.kv_keys()
+            }
+
+            #[doc = #reconstruct_doc]
+            pub fn reconstruct #impl_generics (
+                &self,
+                storage_values: &[::neutron_sdk::bindings::types::StorageValue],
+            ) -> ::neutron_sdk::NeutronResult<#ident #ty_generics> #where_clause {
+                let kv_keys = self.kv_keys()?;
+                if storage_values.len() != kv_keys.len() {
+                    return ::std::result::Result::Err(::neutron_sdk::NeutronError::InvalidQueryResultFormat(
+                        ::std::format!(
+                            "expected {} storage values, got {}",
+                            kv_keys.len(),
+                            storage_values.len()
+                        ),
+                    ));
+                }
+
+                for (kv, kv_key) in storage_values.iter().zip(kv_keys.iter()) {
+                    if kv.storage_prefix != kv_key.path || kv.key != kv_key.key {
+                        return ::std::result::Result::Err(::neutron_sdk::NeutronError::InvalidQueryResultFormat(
+                            ::std::format!(
+                                "expected key {} in the {} store, got key {} in the {} store",
+                                kv_key.key,
+                                kv_key.path,
+                                kv.key,
+                                kv.storage_prefix
+                            ),
+                        ));
+                    }
+                }
+
+                <#ident #ty_generics as ::neutron_sdk::interchain_queries::types::KVReconstruct>::reconstruct(
+                    storage_values,
+                )
+            }
+        }
+
+        impl #impl_generics ::neutron_sdk::interchain_queries::types::KVReconstruct for #ident #ty_generics #where_clause {
+            fn reconstruct(
+                storage_values: &[::neutron_sdk::bindings::types::StorageValue],
+            ) -> ::neutron_sdk::NeutronResult<Self> {
+                if storage_values.len() != #values_count {
+                    return ::std::result::Result::Err(::neutron_sdk::NeutronError::InvalidQueryResultFormat(
+                        ::std::format!(
+                            "expected {} storage values, got {}",
+                            #values_count,
+                            storage_values.len()
+                        ),
+                    ));
+                }
+
+                ::std::result::Result::Ok(Self {
+                    #(#reconstruct_fields,)*
+                })
+            }
+
+            fn kv_count() -> ::std::option::Option<usize> {
+                ::std::option::Option::Some(#values_count)
+            }
+        }
+    })
+}
+
+/// Generates decoding of the storage value **kv** into the field
+fn decode_value(kv_field: &KVField) -> TokenStream2 {
+    let (ty, optional) = match option_inner_type(&kv_field.field.ty) {
+        Some(inner) => (inner, true),
+        None => (&kv_field.field.ty, false),
+    };
+
+    let decoded = match kv_field.decoder {
+        Decoder::Protobuf => quote! {
+            ::neutron_sdk::interchain_queries::helpers::decode_protobuf_value::<#ty>(kv)?
+        },
+        Decoder::Json => quote! {
+            ::neutron_sdk::interchain_queries::helpers::decode_json_value::<#ty>(kv)?
+        },
+        Decoder::Raw => quote! { kv.value.clone() },
+    };
+
+    if optional {
+        quote! {
+            if kv.value.is_empty() {
+                ::std::option::Option::None
+            } else {
+                ::std::option::Option::Some(#decoded)
+            }
+        }
+    } else {
+        decoded
+    }
+}
+
+/// Returns **T** if **ty** is **Option<T>**
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Parses params of the keys struct from the struct **kv** attribute, `#[kv(keys(name: Type, ...))]`
+fn parse_key_params(attrs: &[Attribute]) -> Result<Vec<KeyParam>> {
+    let mut params: Vec<KeyParam> = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("kv")) {
+        let parsed = attr.parse_args_with(|input: ParseStream| {
+            let ident: Ident = input.parse()?;
+            if ident != "keys" {
+                return Err(Error::new(
+                    ident.span(),
+                    "unknown kv attribute, expected #[kv(keys(name: Type, ...))]",
+                ));
+            }
+
+            let content;
+            parenthesized!(content in input);
+            content.parse_terminated::<KeyParam, Token![,]>(KeyParam::parse)
+        })?;
+
+        params.extend(parsed);
+    }
+
+    Ok(params)
+}
+
+/// Parses **kv** attribute of the field
+fn parse_kv_field(field: &Field) -> Result<KVField> {
+    let mut path: Option<LitStr> = None;
+    let mut key: Option<Expr> = None;
+    let mut decoder = Decoder::Protobuf;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("kv")) {
+        let args = attr.parse_args_with(Punctuated::<FieldArg, Token![,]>::parse_terminated)?;
+
+        for arg in args {
+            match arg {
+                FieldArg::Path(lit) => path = Some(lit),
+                FieldArg::Key(expr) => key = Some(expr),
+                FieldArg::Decoder(d) => decoder = d,
+            }
+        }
+    }
+
+    let path = path.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "missing store path, add #[kv(path = \"...\")] to the field",
+        )
+    })?;
+    let key = key.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "missing key, add #[kv(key = ...)] to the field",
+        )
+    })?;
+
+    Ok(KVField {
+        field,
+        path,
+        key,
+        decoder,
+    })
+}
@@ -1,5 +1,6 @@
 use crate::bindings::types::{KVKey, ProtobufAny};
-use cosmwasm_std::{CosmosMsg, CustomMsg};
+use crate::sudo::msg::RequestPacketTimeoutHeight;
+use cosmwasm_std::{Coin, CosmosMsg, CustomMsg};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -73,6 +74,37 @@ pub enum NeutronMsg {
         /// **query_id** is ID of the query we want to remove
         query_id: u64,
     },
+
+    /// IbcTransfer sends a fungible token packet over IBC from the contract address.
+    /// The result of the transfer is delivered to the contract via sudo call (**SudoMsg::Response**,
+    /// **SudoMsg::Error** or **SudoMsg::Timeout**) with the packet's source channel and sequence.
+    IbcTransfer {
+        /// **source_port** is the port on which the packet will be sent
+        source_port: String,
+
+        /// **source_channel** is the channel by which the packet will be sent
+        source_channel: String,
+
+        /// **token** is the tokens to be transferred
+        token: Coin,
+
+        /// **receiver** is the recipient address on the destination chain
+        receiver: String,
+
+        /// **timeout_height** is the timeout height relative to the current block height on the destination chain.
+        /// The timeout is disabled when set to 0
+        timeout_height: RequestPacketTimeoutHeight,
+
+        /// **timeout_timestamp** is the timeout timestamp in absolute nanoseconds since unix epoch.
+        /// The timeout is disabled when set to 0
+        timeout_timestamp: u64,
+
+        /// **memo** is a memo you want to attach to the transfer packet
+        memo: String,
+
+        /// **fee** is the fee paid to relayers for delivering the acknowledgement or the timeout of the packet
+        fee: IbcFee,
+    },
 }
 
 impl NeutronMsg {
@@ -157,6 +189,38 @@ impl NeutronMsg {
     pub fn remove_interchain_query(query_id: u64) -> Self {
         NeutronMsg::RemoveInterchainQuery { query_id }
     }
+
+    #[allow(clippy::too_many_arguments)]
+    /// Basic helper to define an IBC transfer message:
+    /// * **source_port** is the port on which the packet will be sent;
+    /// * **source_channel** is the channel by which the packet will be sent;
+    /// * **token** is the tokens to be transferred;
+    /// * **receiver** is the recipient address on the destination chain;
+    /// * **timeout_height** is the timeout height on the destination chain, 0 disables the timeout;
+    /// * **timeout_timestamp** is the timeout timestamp in nanoseconds, 0 disables the timeout;
+    /// * **memo** is a memo you want to attach to the transfer packet;
+    /// * **fee** is the fee paid to relayers for delivering the acknowledgement or the timeout of the packet.
+    pub fn ibc_transfer(
+        source_port: String,
+        source_channel: String,
+        token: Coin,
+        receiver: String,
+        timeout_height: RequestPacketTimeoutHeight,
+        timeout_timestamp: u64,
+        memo: String,
+        fee: IbcFee,
+    ) -> Self {
+        NeutronMsg::IbcTransfer {
+            source_port,
+            source_channel,
+            token,
+            receiver,
+            timeout_height,
+            timeout_timestamp,
+            memo,
+            fee,
+        }
+    }
 }
 
 impl From<NeutronMsg> for CosmosMsg<NeutronMsg> {
@@ -174,3 +238,95 @@ pub struct MsgRegisterInterchainQueryResponse {
     /// **id** is an identifier of newly registered interchain query
     pub id: u64,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes fees paid to relayers for delivering the result of an IBC packet
+pub struct IbcFee {
+    /// **ack_fee** is the fee paid to a relayer for delivering the acknowledgement of the packet
+    pub ack_fee: Vec<Coin>,
+
+    /// **timeout_fee** is the fee paid to a relayer for delivering the timeout of the packet
+    pub timeout_fee: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes response structure for **IbcTransfer** msg
+pub struct MsgIbcTransferResponse {
+    /// **sequence_id** is a sequence number of the sent IBC packet
+    pub sequence_id: u64,
+
+    /// **channel** is a source channel of the sent IBC packet
+    pub channel: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IbcFee, MsgIbcTransferResponse, NeutronMsg};
+    use crate::interchain_txs::helpers::decode_message_response;
+    use crate::sudo::msg::RequestPacketTimeoutHeight;
+    use cosmwasm_std::{Binary, Coin, Reply, SubMsgResponse, SubMsgResult};
+
+    #[test]
+    fn test_ibc_transfer_serialization() {
+        let msg = NeutronMsg::ibc_transfer(
+            "transfer".to_string(),
+            "channel-0".to_string(),
+            Coin::new(1000, "untrn"),
+            "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+            RequestPacketTimeoutHeight {
+                revision_number: Some(2),
+                revision_height: Some(100),
+            },
+            0,
+            "memo".to_string(),
+            IbcFee {
+                ack_fee: vec![Coin::new(1000, "untrn")],
+                timeout_fee: vec![Coin::new(2000, "untrn")],
+            },
+        );
+
+        let json = serde_json_wasm::to_string(&msg).unwrap();
+        assert_eq!(
+            json,
+            r#"{"ibc_transfer":{"source_port":"transfer","source_channel":"channel-0","token":{"denom":"untrn","amount":"1000"},"receiver":"osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs","timeout_height":{"revision_number":2,"revision_height":100},"timeout_timestamp":0,"memo":"memo","fee":{"ack_fee":[{"denom":"untrn","amount":"1000"}],"timeout_fee":[{"denom":"untrn","amount":"2000"}]}}}"#
+        );
+        assert_eq!(serde_json_wasm::from_str::<NeutronMsg>(&json).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_ibc_transfer_response_decoding() {
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(
+                    br#"{"sequence_id":5,"channel":"channel-0"}"#.as_slice(),
+                )),
+            }),
+        };
+        assert_eq!(
+            decode_message_response::<MsgIbcTransferResponse>(reply).unwrap(),
+            MsgIbcTransferResponse {
+                sequence_id: 5,
+                channel: "channel-0".to_string(),
+            }
+        );
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        };
+        assert!(decode_message_response::<MsgIbcTransferResponse>(reply).is_err());
+
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Err("insufficient funds".to_string()),
+        };
+        assert!(decode_message_response::<MsgIbcTransferResponse>(reply).is_err());
+    }
+}
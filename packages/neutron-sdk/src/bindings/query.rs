@@ -1,5 +1,5 @@
-use crate::bindings::types::{InterchainQueryResult, RegisteredQuery};
-use cosmwasm_std::CustomQuery;
+use crate::bindings::types::{InterchainQueryResult, PageRequest, PageResponse, RegisteredQuery};
+use cosmwasm_std::{Coin, CustomQuery};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -27,14 +27,33 @@ pub enum InterchainQueries {
         connection_id: String,
     },
 
-    /// Query all registered interchain queries on all remote chains
-    RegisteredInterchainQueries {},
+    /// Query registered interchain queries on all remote chains.
+    /// All the filters are optional, the queries matching all the set filters are returned
+    RegisteredInterchainQueries {
+        /// **owner** is an address of the queries owner
+        owner: Option<String>,
+
+        /// **connection_id** is an IBC connection identifier between Neutron and remote chain
+        connection_id: Option<String>,
+
+        /// **query_type** is a type of the queries ('kv' or 'tx')
+        query_type: Option<String>,
+
+        /// **pagination** is a pagination of the result
+        pagination: Option<PageRequest>,
+    },
 
     /// Query registered interchain query with a specific queiry_id
     RegisteredInterchainQuery {
         /// **query_id** is an ID registered interchain query
         query_id: u64,
     },
+
+    /// Query the params of the interchainqueries module
+    InterchainQueriesParams {},
+
+    /// Query the params of the interchaintxs module
+    InterchainTxsParams {},
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -42,6 +61,10 @@ pub enum InterchainQueries {
 pub struct QueryRegisteredQueriesResponse {
     /// **registered_queries** is a list of registered queries
     pub registered_queries: Vec<RegisteredQuery>,
+
+    /// **pagination** is a pagination of the result
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -64,4 +87,50 @@ pub struct QueryInterchainAccountAddressResponse {
     pub interchain_account_address: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes params of the interchainqueries module
+pub struct InterchainQueriesParams {
+    /// **query_submit_timeout** is a number of blocks after which the query is considered stale
+    /// and anyone is able to remove it with getting the deposit
+    #[serde(default)]
+    pub query_submit_timeout: u64,
+
+    /// **query_deposit** is a deposit charged from the query owner at the query registration.
+    /// It's returned to the owner when the query is removed
+    #[serde(default)]
+    pub query_deposit: Vec<Coin>,
+
+    /// **max_kv_query_keys_count** is a maximum number of keys of a KV interchain query
+    #[serde(default)]
+    pub max_kv_query_keys_count: u64,
+
+    /// **max_transactions_filters** is a maximum number of filters of a TX interchain query
+    #[serde(default)]
+    pub max_transactions_filters: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryInterchainQueriesParamsResponse {
+    /// **params** is the params of the interchainqueries module
+    pub params: InterchainQueriesParams,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes params of the interchaintxs module
+pub struct InterchainTxsParams {
+    /// **msg_submit_tx_max_messages** is a maximum number of messages in a single **SubmitTx** message
+    #[serde(default)]
+    pub msg_submit_tx_max_messages: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryInterchainTxsParamsResponse {
+    /// **params** is the params of the interchaintxs module
+    pub params: InterchainTxsParams,
+}
+
 impl CustomQuery for InterchainQueries {}
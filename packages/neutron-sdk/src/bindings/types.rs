@@ -1,3 +1,8 @@
+use crate::{NeutronError, NeutronResult};
+use cosmos_sdk_proto::cosmos::{authz, bank, distribution, gov, staking};
+use cosmos_sdk_proto::ibc::applications::transfer;
+use osmosis_std::types::osmosis::gamm;
+use prost::Message;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -78,6 +83,42 @@ pub struct StorageValue {
     pub value: Binary,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes pagination parameters of a query, follows the Cosmos SDK **PageRequest** structure
+pub struct PageRequest {
+    /// **key** is a value returned in **PageResponse.next_key** to begin querying the next page most efficiently.
+    /// Only one of **offset** or **key** should be set
+    pub key: Binary,
+
+    /// **offset** is a numeric offset that can be used when **key** is unavailable
+    pub offset: u64,
+
+    /// **limit** is the total number of results to be returned in the result page.
+    /// If left empty it will default to a value to be set by each app
+    pub limit: u64,
+
+    /// **count_total** is set to true to indicate that the result set should include a count of the total number of items available for pagination
+    pub count_total: bool,
+
+    /// **reverse** is set to true if results are to be returned in the descending order
+    pub reverse: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes pagination of a query result, follows the Cosmos SDK **PageResponse** structure
+pub struct PageResponse {
+    /// **next_key** is the key to be passed to **PageRequest.key** to query the next page most efficiently.
+    /// It will be empty if there are no more results
+    #[serde(default)]
+    pub next_key: Option<Binary>,
+
+    /// **total** is total number of results available if **PageRequest.count_total** was set, its value is undefined otherwise
+    #[serde(default)]
+    pub total: u64,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
 #[serde(rename_all = "snake_case")]
 /// Type for wrapping any protobuf message
@@ -96,6 +137,78 @@ impl ProtobufAny {
     pub fn new(type_url: String, value: Binary) -> Self {
         ProtobufAny { type_url, value }
     }
+
+    /// Decodes the wrapped message into **T**.
+    /// Returns an error if **type_url** does not match the type url of **T**
+    pub fn decode_as<T: ToProtobufAny>(&self) -> NeutronResult<T> {
+        if self.type_url != T::TYPE_URL {
+            return Err(NeutronError::InvalidProtobufTypeUrl {
+                expected: T::TYPE_URL.to_string(),
+                actual: self.type_url.clone(),
+            });
+        }
+
+        Ok(T::decode(self.value.as_slice())?)
+    }
+}
+
+/// Describes a protobuf message which can be wrapped into **ProtobufAny**
+/// with the type url known in advance
+pub trait ToProtobufAny: Message + Default {
+    /// **TYPE_URL** describes the type of the serialized message
+    const TYPE_URL: &'static str;
+
+    /// Encodes the message and wraps it into **ProtobufAny** with the message's type url
+    fn to_protobuf_any(&self) -> ProtobufAny {
+        ProtobufAny::new(
+            Self::TYPE_URL.to_string(),
+            Binary::from(self.encode_to_vec()),
+        )
+    }
+}
+
+macro_rules! impl_to_protobuf_any {
+    ($($msg:ty => $type_url:literal),* $(,)?) => {
+        $(
+            impl ToProtobufAny for $msg {
+                const TYPE_URL: &'static str = $type_url;
+            }
+        )*
+    };
+}
+
+impl_to_protobuf_any! {
+    bank::v1beta1::MsgSend => "/cosmos.bank.v1beta1.MsgSend",
+    bank::v1beta1::MsgMultiSend => "/cosmos.bank.v1beta1.MsgMultiSend",
+
+    staking::v1beta1::MsgDelegate => "/cosmos.staking.v1beta1.MsgDelegate",
+    staking::v1beta1::MsgUndelegate => "/cosmos.staking.v1beta1.MsgUndelegate",
+    staking::v1beta1::MsgBeginRedelegate => "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+
+    distribution::v1beta1::MsgWithdrawDelegatorReward =>
+        "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward",
+    distribution::v1beta1::MsgSetWithdrawAddress =>
+        "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress",
+    distribution::v1beta1::MsgWithdrawValidatorCommission =>
+        "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission",
+    distribution::v1beta1::MsgFundCommunityPool =>
+        "/cosmos.distribution.v1beta1.MsgFundCommunityPool",
+
+    gov::v1beta1::MsgSubmitProposal => "/cosmos.gov.v1beta1.MsgSubmitProposal",
+    gov::v1beta1::MsgVote => "/cosmos.gov.v1beta1.MsgVote",
+    gov::v1beta1::MsgVoteWeighted => "/cosmos.gov.v1beta1.MsgVoteWeighted",
+    gov::v1beta1::MsgDeposit => "/cosmos.gov.v1beta1.MsgDeposit",
+
+    transfer::v1::MsgTransfer => "/ibc.applications.transfer.v1.MsgTransfer",
+
+    authz::v1beta1::MsgGrant => "/cosmos.authz.v1beta1.MsgGrant",
+    authz::v1beta1::MsgExec => "/cosmos.authz.v1beta1.MsgExec",
+    authz::v1beta1::MsgRevoke => "/cosmos.authz.v1beta1.MsgRevoke",
+
+    gamm::v1beta1::MsgSwapExactAmountIn => "/osmosis.gamm.v1beta1.MsgSwapExactAmountIn",
+    gamm::v1beta1::MsgSwapExactAmountOut => "/osmosis.gamm.v1beta1.MsgSwapExactAmountOut",
+    gamm::v1beta1::MsgJoinPool => "/osmosis.gamm.v1beta1.MsgJoinPool",
+    gamm::v1beta1::MsgExitPool => "/osmosis.gamm.v1beta1.MsgExitPool",
 }
 
 const KV_PATH_KEY_DELIMITER: &str = "/";
@@ -170,3 +283,35 @@ impl Into<String> for KVKeys {
             .join(KV_KEYS_DELIMITER)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ProtobufAny, ToProtobufAny};
+    use crate::NeutronError;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgDelegate, MsgUndelegate};
+
+    #[test]
+    fn test_protobuf_any_roundtrip() {
+        let msg = MsgDelegate {
+            delegator_address: "cosmos1delegator".to_string(),
+            validator_address: "cosmosvaloper1validator".to_string(),
+            amount: Some(Coin {
+                denom: "stake".to_string(),
+                amount: "1000".to_string(),
+            }),
+        };
+
+        let any: ProtobufAny = msg.to_protobuf_any();
+        assert_eq!(any.type_url, "/cosmos.staking.v1beta1.MsgDelegate");
+        assert_eq!(any.decode_as::<MsgDelegate>().unwrap(), msg);
+
+        assert_eq!(
+            any.decode_as::<MsgUndelegate>().unwrap_err(),
+            NeutronError::InvalidProtobufTypeUrl {
+                expected: "/cosmos.staking.v1beta1.MsgUndelegate".to_string(),
+                actual: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+            }
+        );
+    }
+}
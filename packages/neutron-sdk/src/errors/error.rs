@@ -1,4 +1,4 @@
-use cosmwasm_std::{DecimalRangeExceeded, OverflowError, StdError};
+use cosmwasm_std::{Coin, DecimalRangeExceeded, OverflowError, StdError};
 use serde_json_wasm;
 use thiserror::Error;
 
@@ -24,6 +24,9 @@ pub enum NeutronError {
     #[error("address length should be max {max:?} bytes, got {actual:?}")]
     MaxAddrLength { max: usize, actual: usize },
 
+    #[error("cw-storage-plus namespace length should be max {max:?} bytes, got {actual:?}")]
+    MaxNamespaceLength { max: usize, actual: usize },
+
     #[error("invalid reply id: {0}")]
     InvalidReplyID(u64),
 
@@ -36,9 +39,46 @@ pub enum NeutronError {
     #[error("Overflow error")]
     OverflowError(#[from] OverflowError),
 
+    #[error("invalid protobuf type url: expected {expected:?}, got {actual:?}")]
+    InvalidProtobufTypeUrl { expected: String, actual: String },
+
     #[error("Invalid query result format: {0}")]
     InvalidQueryResultFormat(String),
 
+    #[error("invalid protobuf timestamp: {seconds:?} seconds, {nanos:?} nanos")]
+    InvalidTimestamp { seconds: i64, nanos: i32 },
+
+    #[error("too many kv query keys: max {max:?}, got {actual:?}")]
+    TooManyKVQueryKeys { max: u64, actual: u64 },
+
+    #[error("kv query must have at least one key")]
+    EmptyKVKeys {},
+
+    #[error("invalid kv key: {0}")]
+    InvalidKVKey(String),
+
+    #[error("update period must be positive")]
+    ZeroUpdatePeriod {},
+
+    #[error("too many transactions filter conditions: max {max:?}, got {actual:?}")]
+    TooManyTransactionFilters { max: u64, actual: u64 },
+
+    #[error("invalid transactions filter: {0}")]
+    InvalidTransactionsFilter(String),
+
+    #[error("insufficient funds for interchain query deposit: required {required}, got {actual}")]
+    InsufficientQueryDeposit { required: Coin, actual: Coin },
+
+    #[error("stale interchain query {query_id:?} result: submitted at local height {local_height:?}, remote height {remote_height:?}")]
+    StaleQueryResult {
+        query_id: u64,
+        local_height: u64,
+        remote_height: u64,
+    },
+
+    #[error("too many messages in SubmitTx: max {max:?}, got {actual:?}")]
+    TooManySubmitTxMessages { max: u64, actual: u64 },
+
     #[error("Integration tests mock is active")]
     IntegrationTestsMock {},
 }
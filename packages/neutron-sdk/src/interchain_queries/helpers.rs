@@ -1,8 +1,21 @@
+use crate::bindings::types::{ProtobufAny, StorageValue};
 use crate::errors::error::{NeutronError, NeutronResult};
 use crate::interchain_queries::types::{
-    AddressBytes, BALANCES_PREFIX, DELEGATION_KEY, MAX_ADDR_LEN, PARAMS_STORE_DELIMITER,
-    VALIDATORS_KEY,
+    AddressBytes, ACCOUNT_ADDRESS_PREFIX, AUTHZ_GRANT_KEY_PREFIX, BALANCES_PREFIX,
+    CONSENSUS_ADDR_LEN, DELEGATION_KEY, DELEGATOR_STARTING_INFO_PREFIX, DENOM_METADATA_PREFIX,
+    DENOM_TRACE_KEY_PREFIX, ED25519_PUBKEY_TYPE_URL, FEE_ALLOWANCE_KEY_PREFIX,
+    GAMM_POOLS_KEY_PREFIX, IBC_DENOM_HASH_LEN, IBC_DENOM_PREFIX, MAX_ADDR_LEN,
+    MAX_CW_STORAGE_NAMESPACE_LEN, PARAMS_STORE_DELIMITER, PROPOSALS_KEY_PREFIX, REDELEGATION_KEY,
+    SUPPLY_PREFIX, UNBONDING_DELEGATION_KEY, VALIDATORS_KEY, VALIDATOR_CURRENT_REWARDS_PREFIX,
+    VALIDATOR_HISTORICAL_REWARDS_PREFIX, VALIDATOR_SIGNING_INFO_KEY_PREFIX,
+    VALIDATOR_SLASH_EVENT_PREFIX, VOTES_KEY_PREFIX, WASM_CONTRACT_STORE_PREFIX,
 };
+use bech32::{ToBase32, Variant};
+use cosmos_sdk_proto::cosmos::crypto::ed25519::PubKey;
+use cosmwasm_std::{from_binary, StdError};
+use prost::Message as ProstMessage;
+use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
 
 /// Creates KV key to get **module** param by **key**
 pub fn create_params_store_key(module: &str, key: &str) -> Vec<u8> {
@@ -22,6 +35,18 @@ pub fn decode_and_convert(encoded: &str) -> NeutronResult<AddressBytes> {
     Ok(bech32::convert_bits(&bytes, 5, 8, false)?)
 }
 
+/// Encodes **addr** bytes into a bech32 string with **hrp** human readable part, e.g. **cosmosvalcons**
+pub fn encode_and_convert<AddrBytes: AsRef<[u8]>>(
+    hrp: &str,
+    addr: AddrBytes,
+) -> NeutronResult<String> {
+    Ok(bech32::encode(
+        hrp,
+        addr.as_ref().to_base32(),
+        Variant::Bech32,
+    )?)
+}
+
 /// Prefixes the address bytes with its length
 pub fn length_prefix<AddrBytes: AsRef<[u8]>>(addr: AddrBytes) -> NeutronResult<Vec<u8>> {
     let bz_length = addr.as_ref().len();
@@ -43,6 +68,24 @@ pub fn length_prefix<AddrBytes: AsRef<[u8]>>(addr: AddrBytes) -> NeutronResult<V
     Ok(p)
 }
 
+/// Splits length prefixed address off the beginning of **bz**.
+/// Returns the address and the rest of the bytes
+pub fn decode_length_prefixed(bz: &[u8]) -> NeutronResult<(&[u8], &[u8])> {
+    let (bz_length, rest) = bz.split_first().ok_or_else(|| {
+        NeutronError::InvalidQueryResultFormat("length prefixed bytes are empty".into())
+    })?;
+
+    if rest.len() < *bz_length as usize {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "length prefix {} exceeds the length of bytes {}",
+            bz_length,
+            rest.len()
+        )));
+    }
+
+    Ok(rest.split_at(*bz_length as usize))
+}
+
 /// Creates balances Cosmos-SDK storage prefix for account with **addr**
 /// https://github.com/cosmos/cosmos-sdk/blob/ad9e5620fb3445c716e9de45cfcdb56e8f1745bf/x/bank/types/key.go#L55
 pub fn create_account_balances_prefix<AddrBytes: AsRef<[u8]>>(
@@ -65,6 +108,27 @@ pub fn create_account_denom_balance_key<AddrBytes: AsRef<[u8]>, S: AsRef<str>>(
     Ok(account_balance_key)
 }
 
+/// Creates **denom** total supply Cosmos-SDK storage key
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/keeper/keeper.go
+pub fn create_total_denom_key<S: AsRef<str>>(denom: S) -> Vec<u8> {
+    let mut total_supply_key: Vec<u8> = vec![SUPPLY_PREFIX];
+    total_supply_key.extend_from_slice(denom.as_ref().as_bytes());
+
+    total_supply_key
+}
+
+/// Creates **denom** metadata Cosmos-SDK storage key.
+/// Denom metadata is stored under the **denom** key in the store prefixed with **denom**,
+/// so the denom is present in the key twice
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/keeper/keeper.go
+pub fn create_denom_metadata_key<S: AsRef<str>>(denom: S) -> Vec<u8> {
+    let mut denom_metadata_key: Vec<u8> = vec![DENOM_METADATA_PREFIX];
+    denom_metadata_key.extend_from_slice(denom.as_ref().as_bytes());
+    denom_metadata_key.extend_from_slice(denom.as_ref().as_bytes());
+
+    denom_metadata_key
+}
+
 /// Creates delegations Cosmos-SDK storage prefix for delegator with **delegator_addr**
 /// https://github.com/cosmos/cosmos-sdk/blob/ad9e5620fb3445c716e9de45cfcdb56e8f1745bf/x/staking/types/keys.go#L181
 pub fn create_delegations_key<AddrBytes: AsRef<[u8]>>(
@@ -88,6 +152,56 @@ pub fn create_delegation_key<AddrBytes: AsRef<[u8]>>(
     Ok(delegations_key)
 }
 
+/// Creates unbonding delegations Cosmos-SDK storage prefix for delegator with **delegator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub fn create_unbonding_delegations_key<AddrBytes: AsRef<[u8]>>(
+    delegator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![UNBONDING_DELEGATION_KEY];
+    key.extend_from_slice(length_prefix(delegator_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK storage key for unbonding delegation between delegator with **delegator_addr** and validator with **validator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub fn create_unbonding_delegation_key<AddrBytes: AsRef<[u8]>>(
+    delegator_address: AddrBytes,
+    validator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut unbonding_delegations_key: Vec<u8> =
+        create_unbonding_delegations_key(delegator_address)?;
+    unbonding_delegations_key.extend_from_slice(length_prefix(validator_address)?.as_slice());
+
+    Ok(unbonding_delegations_key)
+}
+
+/// Creates redelegations Cosmos-SDK storage prefix for delegator with **delegator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub fn create_redelegations_key<AddrBytes: AsRef<[u8]>>(
+    delegator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![REDELEGATION_KEY];
+    key.extend_from_slice(length_prefix(delegator_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK storage key for redelegation of delegator with **delegator_addr**
+/// from validator with **src_validator_addr** to validator with **dst_validator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub fn create_redelegation_key<AddrBytes: AsRef<[u8]>>(
+    delegator_address: AddrBytes,
+    src_validator_address: AddrBytes,
+    dst_validator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut redelegation_key: Vec<u8> = create_redelegations_key(delegator_address)?;
+    redelegation_key.extend_from_slice(length_prefix(src_validator_address)?.as_slice());
+    redelegation_key.extend_from_slice(length_prefix(dst_validator_address)?.as_slice());
+
+    Ok(redelegation_key)
+}
+
 /// Creates Cosmos-SDK storage key for validator with **operator_address**
 /// https://github.com/cosmos/cosmos-sdk/blob/f2d94445c0f5f52cf5ed999b81048b575de94964/x/staking/types/keys.go#L55
 pub fn create_validator_key<AddrBytes: AsRef<[u8]>>(
@@ -98,3 +212,290 @@ pub fn create_validator_key<AddrBytes: AsRef<[u8]>>(
 
     Ok(key)
 }
+
+/// Creates Cosmos-SDK distribution storage key for starting info of delegator with **delegator_addr**
+/// delegated to validator with **validator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub fn create_delegator_starting_info_key<AddrBytes: AsRef<[u8]>>(
+    validator_address: AddrBytes,
+    delegator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![DELEGATOR_STARTING_INFO_PREFIX];
+    key.extend_from_slice(length_prefix(validator_address)?.as_slice());
+    key.extend_from_slice(length_prefix(delegator_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK distribution storage key for historical rewards of validator with **validator_addr** for **period**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub fn create_validator_historical_rewards_key<AddrBytes: AsRef<[u8]>>(
+    validator_address: AddrBytes,
+    period: u64,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![VALIDATOR_HISTORICAL_REWARDS_PREFIX];
+    key.extend_from_slice(length_prefix(validator_address)?.as_slice());
+    // the period is little endian encoded in the key, unlike most of the other numbers in Cosmos-SDK keys
+    key.extend_from_slice(&period.to_le_bytes());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK distribution storage key for current rewards of validator with **validator_addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub fn create_validator_current_rewards_key<AddrBytes: AsRef<[u8]>>(
+    validator_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![VALIDATOR_CURRENT_REWARDS_PREFIX];
+    key.extend_from_slice(length_prefix(validator_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK distribution storage key for slash event of validator with **validator_addr**
+/// which happened at **height** and ended the validator's **period**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub fn create_validator_slash_event_key<AddrBytes: AsRef<[u8]>>(
+    validator_address: AddrBytes,
+    height: u64,
+    period: u64,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![VALIDATOR_SLASH_EVENT_PREFIX];
+    key.extend_from_slice(length_prefix(validator_address)?.as_slice());
+    key.extend_from_slice(&height.to_be_bytes());
+    key.extend_from_slice(&period.to_be_bytes());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK gov storage key for proposal with **proposal_id**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/gov/types/keys.go
+pub fn create_gov_proposal_key(proposal_id: u64) -> Vec<u8> {
+    let mut key: Vec<u8> = vec![PROPOSALS_KEY_PREFIX];
+    key.extend_from_slice(&proposal_id.to_be_bytes());
+
+    key
+}
+
+/// Creates Cosmos-SDK gov storage prefix for votes of proposal with **proposal_id**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/gov/types/keys.go
+pub fn create_gov_proposal_votes_key(proposal_id: u64) -> Vec<u8> {
+    let mut key: Vec<u8> = vec![VOTES_KEY_PREFIX];
+    key.extend_from_slice(&proposal_id.to_be_bytes());
+
+    key
+}
+
+/// Creates Cosmos-SDK gov storage key for vote of **voter_addr** for proposal with **proposal_id**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/gov/types/keys.go
+pub fn create_gov_proposal_voter_votes_key<AddrBytes: AsRef<[u8]>>(
+    proposal_id: u64,
+    voter_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = create_gov_proposal_votes_key(proposal_id);
+    key.extend_from_slice(length_prefix(voter_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK auth storage key for account with **addr**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/auth/types/keys.go
+pub fn create_account_key<AddrBytes: AsRef<[u8]>>(addr: AddrBytes) -> Vec<u8> {
+    // unlike most of the other keys, the address isn't length prefixed in the key
+    let mut key: Vec<u8> = vec![ACCOUNT_ADDRESS_PREFIX];
+    key.extend_from_slice(addr.as_ref());
+
+    key
+}
+
+/// Returns Tendermint consensus address of a validator with ed25519 **consensus_pubkey**
+/// (the first 20 bytes of sha256 hash of the key)
+/// https://github.com/tendermint/tendermint/blob/v0.34.21/crypto/ed25519/ed25519.go
+pub fn consensus_address_from_pubkey(
+    consensus_pubkey: &ProtobufAny,
+) -> NeutronResult<AddressBytes> {
+    if consensus_pubkey.type_url != ED25519_PUBKEY_TYPE_URL {
+        return Err(NeutronError::InvalidProtobufTypeUrl {
+            expected: ED25519_PUBKEY_TYPE_URL.to_string(),
+            actual: consensus_pubkey.type_url.clone(),
+        });
+    }
+
+    let pubkey = PubKey::decode(consensus_pubkey.value.as_slice())?;
+
+    Ok(Sha256::digest(&pubkey.key)[..CONSENSUS_ADDR_LEN].to_vec())
+}
+
+/// Creates Cosmos-SDK slashing storage key for signing info of validator with **consensus_address**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/slashing/types/keys.go
+pub fn create_validator_signing_info_key<AddrBytes: AsRef<[u8]>>(
+    consensus_address: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![VALIDATOR_SIGNING_INFO_KEY_PREFIX];
+    key.extend_from_slice(length_prefix(consensus_address)?.as_slice());
+
+    Ok(key)
+}
+
+/// Creates wasm key to get raw value stored under **key** in the storage of the contract with **contract_address**
+/// https://github.com/CosmWasm/wasmd/blob/v0.27.0/x/wasm/types/keys.go
+pub fn create_wasm_contract_store_key<AddrBytes: AsRef<[u8]>, Key: AsRef<[u8]>>(
+    contract_address: AddrBytes,
+    key: Key,
+) -> Vec<u8> {
+    // the contract address isn't length prefixed in the key
+    let mut store_key: Vec<u8> = vec![WASM_CONTRACT_STORE_PREFIX];
+    store_key.extend_from_slice(contract_address.as_ref());
+    store_key.extend_from_slice(key.as_ref());
+
+    store_key
+}
+
+/// Creates raw storage key of a value stored in cw-storage-plus **Map** with **namespace** under composite **keys**.
+/// The namespace and all the keys except the last one are prefixed with their two bytes big-endian lengths,
+/// the last key is appended as is.
+/// For an **Item** the raw key is just its namespace.
+/// https://github.com/CosmWasm/cw-plus/blob/v0.13.4/packages/storage-plus/src/helpers.rs
+pub fn create_cw_storage_map_key<Namespace: AsRef<[u8]>, Key: AsRef<[u8]>>(
+    namespace: Namespace,
+    keys: &[Key],
+) -> NeutronResult<Vec<u8>> {
+    let (last, prefixes) = match keys.split_last() {
+        Some((last, prefixes)) => (last.as_ref(), prefixes),
+        None => (&[] as &[u8], &[] as &[Key]),
+    };
+
+    let mut key: Vec<u8> = cw_storage_length_prefix(namespace)?;
+    for prefix in prefixes {
+        key.extend_from_slice(cw_storage_length_prefix(prefix)?.as_slice());
+    }
+    key.extend_from_slice(last);
+
+    Ok(key)
+}
+
+/// Prefixes the namespace bytes with its two bytes big-endian length like cw-storage-plus does
+fn cw_storage_length_prefix<Namespace: AsRef<[u8]>>(
+    namespace: Namespace,
+) -> NeutronResult<Vec<u8>> {
+    let bz_length = namespace.as_ref().len();
+
+    if bz_length > MAX_CW_STORAGE_NAMESPACE_LEN {
+        return Err(NeutronError::MaxNamespaceLength {
+            max: MAX_CW_STORAGE_NAMESPACE_LEN,
+            actual: bz_length,
+        });
+    }
+
+    let mut p: Vec<u8> = (bz_length as u16).to_be_bytes().to_vec();
+    p.extend_from_slice(namespace.as_ref());
+
+    Ok(p)
+}
+
+/// Creates Osmosis gamm storage key for pool with **pool_id**
+/// https://github.com/osmosis-labs/osmosis/blob/v12.0.0/x/gamm/types/key.go
+pub fn create_gamm_pool_key(pool_id: u64) -> Vec<u8> {
+    let mut key: Vec<u8> = vec![GAMM_POOLS_KEY_PREFIX];
+    key.extend_from_slice(&pool_id.to_be_bytes());
+
+    key
+}
+
+/// Returns IBC denom (**ibc/{hash}**) of a token with **base_denom** transferred through the **path**
+/// (e.g. **transfer/channel-0**). The **base_denom** is returned as is if the **path** is empty
+/// https://github.com/cosmos/ibc-go/blob/v3.0.0/modules/apps/transfer/types/trace.go
+pub fn get_ibc_denom(path: &str, base_denom: &str) -> String {
+    if path.is_empty() {
+        return base_denom.to_string();
+    }
+
+    let hash = Sha256::digest(format!("{}/{}", path, base_denom).as_bytes());
+    let hex_hash: String = hash.iter().map(|b| format!("{:02X}", b)).collect();
+
+    format!("{}{}", IBC_DENOM_PREFIX, hex_hash)
+}
+
+/// Decodes hash of IBC denom from its hex representation, the **ibc/** prefix is optional
+pub fn decode_ibc_denom_hash(denom: &str) -> NeutronResult<Vec<u8>> {
+    let hex_hash = denom.strip_prefix(IBC_DENOM_PREFIX).unwrap_or(denom);
+
+    if hex_hash.len() != IBC_DENOM_HASH_LEN * 2 || !hex_hash.is_ascii() {
+        return Err(NeutronError::Std(StdError::generic_err(format!(
+            "invalid ibc denom hash: {}",
+            denom
+        ))));
+    }
+
+    (0..hex_hash.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex_hash[i..i + 2], 16).map_err(|_| {
+                NeutronError::Std(StdError::generic_err(format!(
+                    "invalid ibc denom hash: {}",
+                    denom
+                )))
+            })
+        })
+        .collect()
+}
+
+/// Creates IBC transfer storage key for denom trace with **hash**
+/// https://github.com/cosmos/ibc-go/blob/v3.0.0/modules/apps/transfer/keeper/keeper.go
+pub fn create_denom_trace_key<Hash: AsRef<[u8]>>(hash: Hash) -> Vec<u8> {
+    let mut key: Vec<u8> = vec![DENOM_TRACE_KEY_PREFIX];
+    key.extend_from_slice(hash.as_ref());
+
+    key
+}
+
+/// Creates Cosmos-SDK authz storage key for grant given by **granter** to **grantee** for **msg_type_url**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/authz/keeper/keys.go
+pub fn create_authz_grant_key<AddrBytes: AsRef<[u8]>, S: AsRef<str>>(
+    granter: AddrBytes,
+    grantee: AddrBytes,
+    msg_type_url: S,
+) -> NeutronResult<Vec<u8>> {
+    let mut key: Vec<u8> = vec![AUTHZ_GRANT_KEY_PREFIX];
+    key.extend_from_slice(length_prefix(granter)?.as_slice());
+    key.extend_from_slice(length_prefix(grantee)?.as_slice());
+    key.extend_from_slice(msg_type_url.as_ref().as_bytes());
+
+    Ok(key)
+}
+
+/// Creates Cosmos-SDK feegrant storage key for fee allowance given by **granter** to **grantee**
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/feegrant/key.go
+pub fn create_fee_allowance_key<AddrBytes: AsRef<[u8]>>(
+    granter: AddrBytes,
+    grantee: AddrBytes,
+) -> NeutronResult<Vec<u8>> {
+    // unlike authz, the grantee goes first in the key
+    let mut key: Vec<u8> = vec![FEE_ALLOWANCE_KEY_PREFIX];
+    key.extend_from_slice(length_prefix(grantee)?.as_slice());
+    key.extend_from_slice(length_prefix(granter)?.as_slice());
+
+    Ok(key)
+}
+
+/// Checks that storage value **kv** is read from the store of the module with **path**
+pub fn check_storage_prefix(kv: &StorageValue, path: &str) -> NeutronResult<()> {
+    if kv.storage_prefix != path {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "expected storage value from {} store, got {}",
+            path, kv.storage_prefix
+        )));
+    }
+
+    Ok(())
+}
+
+/// Decodes protobuf encoded storage value **kv**
+pub fn decode_protobuf_value<T: ProstMessage + Default>(kv: &StorageValue) -> NeutronResult<T> {
+    Ok(T::decode(kv.value.as_slice())?)
+}
+
+/// Decodes JSON encoded storage value **kv**, e.g. a value stored by a CosmWasm contract
+pub fn decode_json_value<T: DeserializeOwned>(kv: &StorageValue) -> NeutronResult<T> {
+    Ok(from_binary(&kv.value)?)
+}
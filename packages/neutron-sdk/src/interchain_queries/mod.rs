@@ -18,6 +18,14 @@ mod register_queries;
 pub mod types;
 
 pub use register_queries::{
-    register_balance_query, register_delegator_delegations_query, register_transfers_query,
-    remove_interchain_query, update_interchain_query,
+    check_query_registration_limits, check_tx_query_registration_limits,
+    handle_register_query_reply, register_account_query, register_authz_grants_query,
+    register_balance_query, register_bank_total_supply_query, register_delegator_delegations_query,
+    register_delegator_rewards_query, register_delegator_unbonding_delegations_query,
+    register_denom_metadata_query, register_denom_traces_query, register_fee_allowance_query,
+    register_gamm_pool_query, register_gov_proposals_query, register_gov_votes_query,
+    register_kv_query, register_redelegations_query, register_transfers_query, register_tx_query,
+    register_validator_signing_info_query, register_validators_info_query,
+    register_wasm_contract_state_query, remove_interchain_query, update_interchain_query,
+    with_reply_id,
 };
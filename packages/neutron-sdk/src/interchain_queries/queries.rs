@@ -11,12 +11,21 @@
 // limitations under the License.
 
 use crate::bindings::query::{
-    InterchainQueries, QueryRegisteredQueryResponse, QueryRegisteredQueryResultResponse,
+    InterchainQueries, InterchainQueriesParams, QueryInterchainQueriesParamsResponse,
+    QueryRegisteredQueriesResponse, QueryRegisteredQueryResponse,
+    QueryRegisteredQueryResultResponse,
+};
+use crate::bindings::types::{PageRequest, RegisteredQuery};
+use crate::interchain_queries::types::{
+    AccountInfo, AuthzGrants, Balances, Delegations, DenomMetadata, DenomTraces, FeeGrantAllowance,
+    GammPool, GovProposalVotes, GovProposals, KVReconstruct, PendingRewards, QueryType,
+    Redelegations, StakingValidators, TotalSupply, UnbondingDelegations, ValidatorSigningInfo,
+    WasmContractState,
 };
-use crate::interchain_queries::types::{Balances, Delegations, KVReconstruct, QueryType};
 use crate::{NeutronError, NeutronResult};
 use cosmwasm_std::{to_binary, Binary, Deps, Env};
 use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -33,6 +42,162 @@ pub struct DelegatorDelegationsResponse {
     pub last_submitted_local_height: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegatorUnbondingDelegationsResponse {
+    pub unbonding_delegations: UnbondingDelegations,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegatorRedelegationsResponse {
+    pub redelegations: Redelegations,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegatorRewardsResponse {
+    pub rewards: PendingRewards,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalResponse {
+    pub proposals: GovProposals,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalVotesResponse {
+    pub votes: GovProposalVotes,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AccountResponse {
+    pub account: AccountInfo,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ValidatorSigningInfoResponse {
+    pub signing_infos: ValidatorSigningInfo,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GammPoolResponse {
+    pub pool: GammPool,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DenomTracesResponse {
+    pub traces: DenomTraces,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AuthzGrantsResponse {
+    pub grants: AuthzGrants,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeAllowanceResponse {
+    pub allowance: FeeGrantAllowance,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct WasmContractStateResponse<T> {
+    pub state: WasmContractState<T>,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes filters of registered interchain queries, unset filters match any query
+pub struct RegisteredQueriesFilter {
+    /// **owner** is an address of the queries owner
+    pub owner: Option<String>,
+
+    /// **connection_id** is an IBC connection identifier between Neutron and remote chain
+    pub connection_id: Option<String>,
+
+    /// **query_type** is a type of the queries
+    pub query_type: Option<QueryType>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes how fresh a result of interchain query must be to be used, unset limits are not checked
+pub struct FreshnessPolicy {
+    /// **max_local_age** is a max number of local blocks passed since the result was submitted
+    pub max_local_age: Option<u64>,
+
+    /// **min_remote_height** is a min remote chain height at which the result must be taken
+    pub min_remote_height: Option<u64>,
+}
+
+impl FreshnessPolicy {
+    /// Checks that the last submitted result of **registered_query** satisfies the policy at the current block
+    pub fn check(&self, env: &Env, registered_query: &RegisteredQuery) -> NeutronResult<()> {
+        let local_height = registered_query.last_submitted_result_local_height;
+        let remote_height = registered_query.last_submitted_result_remote_height;
+
+        let too_old = self.max_local_age.map_or(false, |max_age| {
+            env.block.height.saturating_sub(local_height) > max_age
+        });
+        let too_low = self
+            .min_remote_height
+            .map_or(false, |min_height| remote_height < min_height);
+
+        // a query without any submitted result has no fresh result too
+        if local_height == 0 || too_old || too_low {
+            return Err(NeutronError::StaleQueryResult {
+                query_id: registered_query.id,
+                local_height,
+                remote_height,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TotalSupplyResponse {
+    pub supply: TotalSupply,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DenomMetadataResponse {
+    pub metadata: DenomMetadata,
+    pub last_submitted_local_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ValidatorResponse {
+    pub validators: StakingValidators,
+    pub last_submitted_local_height: u64,
+}
+
 /// Parse **actual** query type, checks that it's valid and assert it with **expected** query type
 pub fn check_query_type(actual: String, expected: QueryType) -> NeutronResult<QueryType> {
     if let Some(t) = QueryType::try_from_str(&actual) {
@@ -60,6 +225,35 @@ fn get_registered_query(
     Ok(res)
 }
 
+/// Queries registered interchain queries matching the **filter**:
+/// * **filter** is a set of filters for the queries, e.g. an owner to list only queries of a particular contract;
+/// * **pagination** is an optional pagination of the result.
+pub fn get_registered_queries(
+    deps: Deps<InterchainQueries>,
+    filter: RegisteredQueriesFilter,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<QueryRegisteredQueriesResponse> {
+    let query = InterchainQueries::RegisteredInterchainQueries {
+        owner: filter.owner,
+        connection_id: filter.connection_id,
+        query_type: filter.query_type.map(Into::into),
+        pagination,
+    };
+
+    let res: QueryRegisteredQueriesResponse = deps.querier.query(&query.into())?;
+    Ok(res)
+}
+
+/// Queries params of the interchainqueries module (query deposit, max keys count, etc.)
+pub fn get_interchain_queries_params(
+    deps: Deps<InterchainQueries>,
+) -> NeutronResult<InterchainQueriesParams> {
+    let query = InterchainQueries::InterchainQueriesParams {};
+
+    let res: QueryInterchainQueriesParamsResponse = deps.querier.query(&query.into())?;
+    Ok(res.params)
+}
+
 /// Queries interchain query result (raw KV storage values or transactions) from Interchain Queries Module
 fn get_interchain_query_result(
     deps: Deps<InterchainQueries>,
@@ -82,6 +276,19 @@ pub fn query_kv_result<T: KVReconstruct>(
     KVReconstruct::reconstruct(&registered_query_result.result.kv_results)
 }
 
+/// Reads submitted raw KV values for Interchain Query with **query_id** from the storage and reconstructs the result.
+/// Returns **NeutronError::StaleQueryResult** if the result is not fresh enough by the **policy**
+pub fn query_fresh_kv_result<T: KVReconstruct>(
+    deps: Deps<InterchainQueries>,
+    env: Env,
+    query_id: u64,
+    policy: FreshnessPolicy,
+) -> NeutronResult<T> {
+    let (result, _) = query_fresh_registered_kv_result(deps, &env, query_id, &policy)?;
+
+    Ok(result)
+}
+
 /// Returns balance of account on remote chain for particular denom
 /// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
 pub fn query_balance(
@@ -124,6 +331,232 @@ pub fn query_delegations(
     })?)
 }
 
+/// Returns balance of account on remote chain for particular denom if the result is fresh enough by the **policy**
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_fresh_balance(
+    deps: Deps<InterchainQueries>,
+    env: Env,
+    registered_query_id: u64,
+    policy: FreshnessPolicy,
+) -> NeutronResult<Binary> {
+    let (balances, last_submitted_local_height) =
+        query_fresh_registered_kv_result::<Balances>(deps, &env, registered_query_id, &policy)?;
+
+    Ok(to_binary(&QueryBalanceResponse {
+        balances,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns delegations of particular delegator on remote chain if the result is fresh enough by the **policy**
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_fresh_delegations(
+    deps: Deps<InterchainQueries>,
+    env: Env,
+    registered_query_id: u64,
+    policy: FreshnessPolicy,
+) -> NeutronResult<Binary> {
+    let (delegations, last_submitted_local_height) =
+        query_fresh_registered_kv_result::<Delegations>(deps, &env, registered_query_id, &policy)?;
+
+    Ok(to_binary(&DelegatorDelegationsResponse {
+        delegations: delegations.delegations,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns unbonding delegations of particular delegator on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_unbonding_delegations(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (unbonding_delegations, last_submitted_local_height) =
+        query_registered_kv_result::<UnbondingDelegations>(deps, registered_query_id)?;
+
+    Ok(to_binary(&DelegatorUnbondingDelegationsResponse {
+        unbonding_delegations,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns redelegations of particular delegator on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_redelegations(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (redelegations, last_submitted_local_height) =
+        query_registered_kv_result::<Redelegations>(deps, registered_query_id)?;
+
+    Ok(to_binary(&DelegatorRedelegationsResponse {
+        redelegations,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns pending rewards of particular delegator on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_delegator_rewards(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (rewards, last_submitted_local_height) =
+        query_registered_kv_result::<PendingRewards>(deps, registered_query_id)?;
+
+    Ok(to_binary(&DelegatorRewardsResponse {
+        rewards,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns list of governance proposals on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_government_proposals(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (proposals, last_submitted_local_height) =
+        query_registered_kv_result::<GovProposals>(deps, registered_query_id)?;
+
+    Ok(to_binary(&ProposalResponse {
+        proposals,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns votes of particular voters for a governance proposal on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_government_proposal_votes(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (votes, last_submitted_local_height) =
+        query_registered_kv_result::<GovProposalVotes>(deps, registered_query_id)?;
+
+    Ok(to_binary(&ProposalVotesResponse {
+        votes,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns info of particular account on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_account(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (account, last_submitted_local_height) =
+        query_registered_kv_result::<AccountInfo>(deps, registered_query_id)?;
+
+    Ok(to_binary(&AccountResponse {
+        account,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns signing infos of particular validators on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_validators_signing_infos(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (signing_infos, last_submitted_local_height) =
+        query_registered_kv_result::<ValidatorSigningInfo>(deps, registered_query_id)?;
+
+    Ok(to_binary(&ValidatorSigningInfoResponse {
+        signing_infos,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns state of Osmosis gamm pool
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_gamm_pool(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (pool, last_submitted_local_height) =
+        query_registered_kv_result::<GammPool>(deps, registered_query_id)?;
+
+    Ok(to_binary(&GammPoolResponse {
+        pool,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns traces of IBC denoms on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_denom_traces(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (traces, last_submitted_local_height) =
+        query_registered_kv_result::<DenomTraces>(deps, registered_query_id)?;
+
+    Ok(to_binary(&DenomTracesResponse {
+        traces,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns **authz** grants given by a granter to a grantee on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_authz_grants(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (grants, last_submitted_local_height) =
+        query_registered_kv_result::<AuthzGrants>(deps, registered_query_id)?;
+
+    Ok(to_binary(&AuthzGrantsResponse {
+        grants,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns fee allowance given by a granter to a grantee on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_fee_allowance(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (allowance, last_submitted_local_height) =
+        query_registered_kv_result::<FeeGrantAllowance>(deps, registered_query_id)?;
+
+    Ok(to_binary(&FeeAllowanceResponse {
+        allowance,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns JSON decoded value stored under the registered key in the storage of a contract on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_wasm_contract_state<T: DeserializeOwned + Serialize>(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (state, last_submitted_local_height) =
+        query_registered_kv_result::<WasmContractState<T>>(deps, registered_query_id)?;
+
+    Ok(to_binary(&WasmContractStateResponse {
+        state,
+        last_submitted_local_height,
+    })?)
+}
+
 /// Queries registered interchain query by **query_id**
 pub fn query_registered_query(
     deps: Deps<InterchainQueries>,
@@ -131,3 +564,90 @@ pub fn query_registered_query(
 ) -> NeutronResult<Binary> {
     Ok(to_binary(&get_registered_query(deps, query_id)?)?)
 }
+
+/// Checks that the registered query with **registered_query_id** is a KV query and reconstructs its result.
+/// Returns the reconstructed result and the local height at which the result was submitted
+fn query_registered_kv_result<T: KVReconstruct>(
+    deps: Deps<InterchainQueries>,
+    registered_query_id: u64,
+) -> NeutronResult<(T, u64)> {
+    let registered_query = get_registered_query(deps, registered_query_id)?;
+
+    check_query_type(registered_query.registered_query.query_type, QueryType::KV)?;
+
+    let result: T = query_kv_result(deps, registered_query.registered_query.id)?;
+
+    Ok((
+        result,
+        registered_query
+            .registered_query
+            .last_submitted_result_local_height,
+    ))
+}
+
+/// Checks that the registered query with **registered_query_id** is a KV query which result is fresh enough
+/// by the **policy** and reconstructs the result.
+/// Returns the reconstructed result and the local height at which the result was submitted
+fn query_fresh_registered_kv_result<T: KVReconstruct>(
+    deps: Deps<InterchainQueries>,
+    env: &Env,
+    registered_query_id: u64,
+    policy: &FreshnessPolicy,
+) -> NeutronResult<(T, u64)> {
+    let registered_query = get_registered_query(deps, registered_query_id)?.registered_query;
+
+    check_query_type(registered_query.query_type.clone(), QueryType::KV)?;
+    policy.check(env, &registered_query)?;
+
+    let result: T = query_kv_result(deps, registered_query.id)?;
+
+    Ok((result, registered_query.last_submitted_result_local_height))
+}
+
+/// Returns total supply of particular denoms on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_bank_total(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (supply, last_submitted_local_height) =
+        query_registered_kv_result::<TotalSupply>(deps, registered_query_id)?;
+
+    Ok(to_binary(&TotalSupplyResponse {
+        supply,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns metadata of particular denoms on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_denom_metadata(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (metadata, last_submitted_local_height) =
+        query_registered_kv_result::<DenomMetadata>(deps, registered_query_id)?;
+
+    Ok(to_binary(&DenomMetadataResponse {
+        metadata,
+        last_submitted_local_height,
+    })?)
+}
+
+/// Returns info of particular validators on remote chain
+/// * ***registered_query_id*** is an identifier of the corresponding registered interchain query
+pub fn query_validators_info(
+    deps: Deps<InterchainQueries>,
+    _env: Env,
+    registered_query_id: u64,
+) -> NeutronResult<Binary> {
+    let (validators, last_submitted_local_height) =
+        query_registered_kv_result::<StakingValidators>(deps, registered_query_id)?;
+
+    Ok(to_binary(&ValidatorResponse {
+        validators,
+        last_submitted_local_height,
+    })?)
+}
@@ -1,23 +1,191 @@
-use crate::bindings::msg::NeutronMsg;
+use crate::bindings::msg::{MsgRegisterInterchainQueryResponse, NeutronMsg};
 use crate::bindings::query::InterchainQueries;
 use crate::bindings::types::{KVKey, KVKeys};
-use crate::errors::error::NeutronResult;
+use crate::errors::error::{NeutronError, NeutronResult};
 use crate::interchain_queries::helpers::{
-    create_account_denom_balance_key, create_delegation_key, create_params_store_key,
-    create_validator_key, decode_and_convert,
+    create_account_denom_balance_key, create_account_key, create_authz_grant_key,
+    create_delegation_key, create_delegator_starting_info_key, create_denom_metadata_key,
+    create_denom_trace_key, create_fee_allowance_key, create_gamm_pool_key,
+    create_gov_proposal_key, create_gov_proposal_voter_votes_key, create_params_store_key,
+    create_redelegation_key, create_total_denom_key, create_unbonding_delegation_key,
+    create_validator_current_rewards_key, create_validator_key, create_validator_signing_info_key,
+    create_validator_slash_event_key, create_wasm_contract_store_key, decode_and_convert,
+    decode_ibc_denom_hash,
 };
+use crate::interchain_queries::queries::get_interchain_queries_params;
 use crate::interchain_queries::types::{
-    QueryType, TransactionFilterItem, TransactionFilterOp, TransactionFilterValue, BANK_STORE_KEY,
-    HEIGHT_FIELD, KEY_BOND_DENOM, PARAMS_STORE_KEY, RECIPIENT_FIELD, STAKING_STORE_KEY,
+    QueryType, TxFilter, ValidatorSlashEventKey, ACCOUNT_STORE_KEY, AUTHZ_STORE_KEY,
+    BANK_STORE_KEY, DISTRIBUTION_STORE_KEY, FEEGRANT_STORE_KEY, GAMM_STORE_KEY, GOV_STORE_KEY,
+    HEIGHT_FIELD, KEY_BOND_DENOM, PARAMS_STORE_KEY, RECIPIENT_FIELD, SLASHING_STORE_KEY,
+    STAKING_STORE_KEY, TRANSFER_STORE_KEY, WASM_STORE_KEY,
 };
-use cosmwasm_std::{attr, Attribute, Binary, DepsMut, Env, Response, StdError};
-use schemars::_serde_json::to_string;
+use crate::interchain_txs::helpers::decode_message_response;
+use cosmwasm_std::{
+    attr, Attribute, Binary, Coin, CosmosMsg, Deps, DepsMut, Env, Reply, ReplyOn, Response,
+};
+
+/// Checks that an Interchain Query with provided **kv_keys** can be registered by the contract:
+/// * the number of keys doesn't exceed **max_kv_query_keys_count** of the interchainqueries module;
+/// * the contract balance is enough to pay **query_deposit** of the interchainqueries module.
+pub fn check_query_registration_limits(
+    deps: Deps<InterchainQueries>,
+    env: &Env,
+    kv_keys: &[KVKey],
+) -> NeutronResult<()> {
+    let params = get_interchain_queries_params(deps)?;
+
+    if kv_keys.len() as u64 > params.max_kv_query_keys_count {
+        return Err(NeutronError::TooManyKVQueryKeys {
+            max: params.max_kv_query_keys_count,
+            actual: kv_keys.len() as u64,
+        });
+    }
+
+    check_query_deposit(deps, env, params.query_deposit)
+}
+
+/// Checks that a TX Interchain Query with provided **filter** can be registered by the contract:
+/// * the number of filter conditions doesn't exceed **max_transactions_filters** of the interchainqueries module;
+/// * the contract balance is enough to pay **query_deposit** of the interchainqueries module.
+pub fn check_tx_query_registration_limits(
+    deps: Deps<InterchainQueries>,
+    env: &Env,
+    filter: &TxFilter,
+) -> NeutronResult<()> {
+    let params = get_interchain_queries_params(deps)?;
+
+    if filter.len() as u64 > params.max_transactions_filters {
+        return Err(NeutronError::TooManyTransactionFilters {
+            max: params.max_transactions_filters,
+            actual: filter.len() as u64,
+        });
+    }
+
+    check_query_deposit(deps, env, params.query_deposit)
+}
+
+/// Checks that the contract balance is enough to pay **query_deposit**
+fn check_query_deposit(
+    deps: Deps<InterchainQueries>,
+    env: &Env,
+    query_deposit: Vec<Coin>,
+) -> NeutronResult<()> {
+    for required in query_deposit {
+        let actual = deps
+            .querier
+            .query_balance(env.contract.address.as_str(), required.denom.as_str())?;
+        if actual.amount < required.amount {
+            return Err(NeutronError::InsufficientQueryDeposit { required, actual });
+        }
+    }
+
+    Ok(())
+}
+
+/// Registers a KV Interchain Query to get raw values stored under **keys** on remote chain.
+/// The result can be read with **query_kv_result** as any type implementing **KVReconstruct**.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **keys** is a list of KV keys to get values of, every key has a path of the module store it's read from;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_kv_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    keys: Vec<KVKey>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    check_update_period(update_period)?;
+
+    if keys.is_empty() {
+        return Err(NeutronError::EmptyKVKeys {});
+    }
+    for key in &keys {
+        if key.path.is_empty() {
+            return Err(NeutronError::InvalidKVKey(format!(
+                "path of key {} is empty",
+                key.key
+            )));
+        }
+        if key.key.is_empty() {
+            return Err(NeutronError::InvalidKVKey(format!(
+                "key in {} store is empty",
+                key.path
+            )));
+        }
+    }
+
+    check_query_registration_limits(deps.as_ref(), &env, &keys)?;
+
+    register_interchain_query(
+        deps,
+        env,
+        connection_id,
+        zone_id,
+        QueryType::KV,
+        keys,
+        String::new(),
+        update_period,
+    )
+}
+
+/// Registers a TX Interchain Query to get transactions matching **filter** on remote chain.
+/// The transactions are passed to the contract with **SudoMsg::TxQueryResult**.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **filter** is a list of conditions on the events of the transactions;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_tx_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    filter: TxFilter,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    check_update_period(update_period)?;
+
+    if filter.is_empty() {
+        return Err(NeutronError::InvalidTransactionsFilter(
+            "filter has no conditions".into(),
+        ));
+    }
+
+    check_tx_query_registration_limits(deps.as_ref(), &env, &filter)?;
+
+    let transactions_filter = filter.to_json()?;
+
+    register_interchain_query(
+        deps,
+        env,
+        connection_id,
+        zone_id,
+        QueryType::TX,
+        vec![],
+        transactions_filter,
+        update_period,
+    )
+}
+
+/// Checks that **update_period** of an Interchain Query is positive
+fn check_update_period(update_period: u64) -> NeutronResult<()> {
+    if update_period == 0 {
+        return Err(NeutronError::ZeroUpdatePeriod {});
+    }
+
+    Ok(())
+}
 
 #[allow(clippy::too_many_arguments)]
-/// Registers an Interchain Query with provided params
+/// Registers an Interchain Query with provided params.
+/// The params must be checked by the caller, e.g. the number of keys and the contract balance
+/// to pay the query deposit with
 fn register_interchain_query(
-    _deps: DepsMut<InterchainQueries>,
-    _env: Env,
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
     connection_id: String,
     zone_id: String,
     query_type: QueryType,
@@ -80,18 +248,123 @@ pub fn register_balance_query(
         key: Binary(balance_key),
     };
 
-    register_interchain_query(
+    register_kv_query(
         deps,
         env,
         connection_id,
         zone_id,
-        QueryType::KV,
         vec![kv_key],
-        String::new(),
         update_period,
     )
 }
 
+/// Registers an Interchain Query to get total supply of particular denoms on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **denoms** is a list of denominations of the coins for which you want to get total supply;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_bank_total_supply_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    denoms: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let kv_keys = denoms
+        .iter()
+        .map(|denom| KVKey {
+            path: BANK_STORE_KEY.to_string(),
+            key: Binary(create_total_denom_key(denom)),
+        })
+        .collect();
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
+/// Registers an Interchain Query to get metadata (units, display denom, etc.) of particular denoms on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **denoms** is a list of denominations of the coins for which you want to get metadata;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_denom_metadata_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    denoms: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let kv_keys = denoms
+        .iter()
+        .map(|denom| KVKey {
+            path: BANK_STORE_KEY.to_string(),
+            key: Binary(create_denom_metadata_key(denom)),
+        })
+        .collect();
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
+/// Registers an Interchain Query to get info (status, tokens, commission, etc.) of particular validators on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **validators** is a list of validators operator addresses for which you want to get info;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_validators_info_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    validators: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let mut kv_keys: Vec<KVKey> = Vec::with_capacity(validators.len());
+
+    for validator in validators {
+        let val_addr = decode_and_convert(&validator)?;
+
+        kv_keys.push(KVKey {
+            path: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_validator_key(&val_addr)?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
+/// Registers an Interchain Query to get signing info (missed blocks, jailing, tombstoning) of particular validators on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **validators** is a list of validators consensus addresses (e.g. **cosmosvalcons1...**) for which you want to get signing info,
+/// a consensus address can be obtained from a validator with `StakingValidator::consensus_address`;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_validator_signing_info_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    validators: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let mut kv_keys: Vec<KVKey> = Vec::with_capacity(validators.len());
+
+    for validator in validators {
+        let cons_addr = decode_and_convert(&validator)?;
+
+        kv_keys.push(KVKey {
+            path: SLASHING_STORE_KEY.to_string(),
+            key: Binary(create_validator_signing_info_key(&cons_addr)?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
 /// Registers an Interchain Query to get delegations of particular delegator on remote chain.
 ///
 /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
@@ -137,69 +410,476 @@ pub fn register_delegator_delegations_query(
         })
     }
 
-    register_interchain_query(
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
+
+/// Registers an Interchain Query to get unbonding delegations of particular delegator on remote chain.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **delegator** is an address of an account on remote chain for which you want to get list of unbonding delegations;
+/// * **validators** is a list of validators addresses for which you want to get unbonding delegations from particular **delegator**;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_delegator_unbonding_delegations_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    delegator: String,
+    validators: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let delegator_addr = decode_and_convert(delegator.as_str())?;
+
+    let mut keys: Vec<KVKey> = Vec::with_capacity(validators.len());
+
+    for v in &validators {
+        // create unbonding delegation key to get unbonding delegation structure
+        let val_addr = decode_and_convert(v.as_str())?;
+        keys.push(KVKey {
+            path: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_unbonding_delegation_key(&delegator_addr, &val_addr)?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
+
+/// Registers an Interchain Query to get redelegations of particular delegator on remote chain.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **delegator** is an address of an account on remote chain for which you want to get list of redelegations;
+/// * **validators_pairs** is a list of (source validator address, destination validator address) pairs
+/// for which you want to get redelegations of particular **delegator**;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_redelegations_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    delegator: String,
+    validators_pairs: Vec<(String, String)>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let delegator_addr = decode_and_convert(delegator.as_str())?;
+
+    let mut keys: Vec<KVKey> = Vec::with_capacity(validators_pairs.len());
+
+    for (src, dst) in &validators_pairs {
+        // create redelegation key to get redelegation structure
+        let src_addr = decode_and_convert(src.as_str())?;
+        let dst_addr = decode_and_convert(dst.as_str())?;
+        keys.push(KVKey {
+            path: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_redelegation_key(
+                &delegator_addr,
+                &src_addr,
+                &dst_addr,
+            )?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Registers an Interchain Query to get pending (not withdrawn yet) rewards of particular delegator on remote chain.
+/// Validator historical rewards keys required to calculate rewards are not known at the moment of registration,
+/// they are returned in **missing_keys** of **PendingRewards** and should be added to the query with `update_interchain_query`.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **delegator** is an address of an account on remote chain for which you want to get rewards;
+/// * **validators** is a list of validators addresses for which you want to get rewards of particular **delegator**;
+/// * **slash_events** is a list of slash events of the **validators** since the delegations started,
+///   the rewards can't be calculated if some of them are missing;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_delegator_rewards_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    delegator: String,
+    validators: Vec<String>,
+    slash_events: Vec<ValidatorSlashEventKey>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let delegator_addr = decode_and_convert(delegator.as_str())?;
+
+    // Allocate memory for such KV keys as:
+    // * validators structures to get validators tokens and shares
+    // * delegations structures to get current delegations shares
+    // * delegators starting infos to get the periods the delegations started at
+    // * validators current rewards to get rewards of the current (unfinished) periods
+    // * validators slash events to get the stake of the delegations after the slashes
+    let mut keys: Vec<KVKey> = Vec::with_capacity(validators.len() * 4 + slash_events.len());
+
+    for v in &validators {
+        let val_addr = decode_and_convert(v.as_str())?;
+
+        keys.push(KVKey {
+            path: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_validator_key(&val_addr)?),
+        });
+
+        keys.push(KVKey {
+            path: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_delegation_key(&delegator_addr, &val_addr)?),
+        });
+
+        keys.push(KVKey {
+            path: DISTRIBUTION_STORE_KEY.to_string(),
+            key: Binary(create_delegator_starting_info_key(
+                &val_addr,
+                &delegator_addr,
+            )?),
+        });
+
+        keys.push(KVKey {
+            path: DISTRIBUTION_STORE_KEY.to_string(),
+            key: Binary(create_validator_current_rewards_key(&val_addr)?),
+        })
+    }
+
+    for event in &slash_events {
+        if !validators.contains(&event.validator) {
+            return Err(NeutronError::InvalidKVKey(format!(
+                "slash event of unknown validator {}",
+                event.validator
+            )));
+        }
+
+        keys.push(KVKey {
+            path: DISTRIBUTION_STORE_KEY.to_string(),
+            key: Binary(create_validator_slash_event_key(
+                decode_and_convert(event.validator.as_str())?,
+                event.height,
+                event.period,
+            )?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
+
+/// Registers an Interchain Query to get governance proposals on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **proposals_ids** is a list of proposals ids from remote chain;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_gov_proposals_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    proposals_ids: Vec<u64>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let mut kv_keys: Vec<KVKey> = Vec::with_capacity(proposals_ids.len());
+
+    for id in proposals_ids {
+        kv_keys.push(KVKey {
+            path: GOV_STORE_KEY.to_string(),
+            key: Binary(create_gov_proposal_key(id)),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
+/// Registers an Interchain Query to get votes of particular voters for a governance proposal on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **proposal_id** is an id of a proposal on remote chain;
+/// * **voters** is a list of voters addresses for which you want to get votes;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_gov_votes_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    proposal_id: u64,
+    voters: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let mut kv_keys: Vec<KVKey> = Vec::with_capacity(voters.len());
+
+    for voter in voters {
+        let voter_addr = decode_and_convert(&voter)?;
+
+        kv_keys.push(KVKey {
+            path: GOV_STORE_KEY.to_string(),
+            key: Binary(create_gov_proposal_voter_votes_key(
+                proposal_id,
+                &voter_addr,
+            )?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, kv_keys, update_period)
+}
+
+/// Registers an Interchain Query to get state (assets, weights, swap fee, etc.) of Osmosis gamm pool
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and Osmosis;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **pool_id** is an identifier of a balancer or stableswap pool;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_gamm_pool_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    pool_id: u64,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let kv_key = KVKey {
+        path: GAMM_STORE_KEY.to_string(),
+        key: Binary(create_gamm_pool_key(pool_id)),
+    };
+
+    register_kv_query(
         deps,
         env,
         connection_id,
         zone_id,
-        QueryType::KV,
-        keys,
-        String::new(),
+        vec![kv_key],
         update_period,
     )
 }
 
-/// Registers an Interchain Query to get transfer events to a recipient on a remote chain.
+/// Registers an Interchain Query to get traces (path and base denom) of IBC denoms on remote chain
 ///
 /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
 /// * **zone_id** is used to identify the chain of interest;
-/// * **recipient** is an address of an account on remote chain for which you want to get list of transfer transactions;
+/// * **hashes** is a list of IBC denoms (**ibc/{hash}**) or just their hashes for which you want to get traces;
 /// * **update_period** is used to say how often the query must be updated.
-/// * **min_height** is used to set min height for query (by default = 0).
-pub fn register_transfers_query(
+pub fn register_denom_traces_query(
     deps: DepsMut<InterchainQueries>,
     env: Env,
     connection_id: String,
     zone_id: String,
-    recipient: String,
+    hashes: Vec<String>,
     update_period: u64,
-    min_height: Option<u128>,
 ) -> NeutronResult<Response<NeutronMsg>> {
-    let mut query_data: Vec<TransactionFilterItem> = vec![TransactionFilterItem {
-        field: RECIPIENT_FIELD.to_string(),
-        op: TransactionFilterOp::Eq,
-        value: TransactionFilterValue::String(recipient),
-    }];
-    if let Some(min_height) = min_height {
-        query_data.push(TransactionFilterItem {
-            field: HEIGHT_FIELD.to_string(),
-            op: TransactionFilterOp::Gte,
-            value: TransactionFilterValue::Int(min_height),
+    let mut keys: Vec<KVKey> = Vec::with_capacity(hashes.len());
+
+    for hash in hashes {
+        keys.push(KVKey {
+            path: TRANSFER_STORE_KEY.to_string(),
+            key: Binary(create_denom_trace_key(decode_ibc_denom_hash(&hash)?)),
         })
     }
-    let query_data_json_encoded =
-        to_string(&query_data).map_err(|e| StdError::generic_err(e.to_string()))?;
 
-    deps.api.debug(
-        format!(
-            "WASMDEBUG: query_data_json_encoded: {:?}",
-            query_data_json_encoded,
-        )
-        .as_str(),
-    );
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
 
-    register_interchain_query(
+#[allow(clippy::too_many_arguments)]
+/// Registers an Interchain Query to get **authz** grants given by **granter** to **grantee** on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **granter** is an address of an account on remote chain which gives the grants;
+/// * **grantee** is an address of an account on remote chain which receives the grants;
+/// * **msg_type_urls** is a list of message types for which you want to get the grants;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_authz_grants_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    granter: String,
+    grantee: String,
+    msg_type_urls: Vec<String>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let granter_addr = decode_and_convert(granter.as_str())?;
+    let grantee_addr = decode_and_convert(grantee.as_str())?;
+
+    let mut keys: Vec<KVKey> = Vec::with_capacity(msg_type_urls.len());
+    for msg_type_url in msg_type_urls {
+        keys.push(KVKey {
+            path: AUTHZ_STORE_KEY.to_string(),
+            key: Binary(create_authz_grant_key(
+                &granter_addr,
+                &grantee_addr,
+                msg_type_url,
+            )?),
+        })
+    }
+
+    register_kv_query(deps, env, connection_id, zone_id, keys, update_period)
+}
+
+/// Registers an Interchain Query to get fee allowance given by **granter** to **grantee** on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **granter** is an address of an account on remote chain which gives the allowance;
+/// * **grantee** is an address of an account on remote chain which receives the allowance;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_fee_allowance_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    granter: String,
+    grantee: String,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let kv_key = KVKey {
+        path: FEEGRANT_STORE_KEY.to_string(),
+        key: Binary(create_fee_allowance_key(
+            decode_and_convert(granter.as_str())?,
+            decode_and_convert(grantee.as_str())?,
+        )?),
+    };
+
+    register_kv_query(
         deps,
         env,
         connection_id,
         zone_id,
-        QueryType::TX,
-        vec![],
-        query_data_json_encoded,
+        vec![kv_key],
+        update_period,
+    )
+}
+
+/// Registers an Interchain Query to get raw value stored under **key** in the storage of a CosmWasm contract
+/// on remote chain. Use **create_cw_storage_map_key** to build the key of a cw-storage-plus **Map** entry,
+/// the key of an **Item** is just its namespace
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **contract_address** is an address of a contract on remote chain;
+/// * **key** is a raw key in the contract's storage;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_wasm_contract_state_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    contract_address: String,
+    key: impl AsRef<[u8]>,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let converted_addr_bytes = decode_and_convert(contract_address.as_str())?;
+
+    let kv_key = KVKey {
+        path: WASM_STORE_KEY.to_string(),
+        key: Binary(create_wasm_contract_store_key(converted_addr_bytes, key)),
+    };
+
+    register_kv_query(
+        deps,
+        env,
+        connection_id,
+        zone_id,
+        vec![kv_key],
         update_period,
     )
 }
 
+/// Registers an Interchain Query to get info (account number, sequence, public key, vesting schedule, etc.)
+/// of particular account on remote chain
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **addr** is an address of an account on remote chain for which you want to get info;
+/// * **update_period** is used to say how often the query must be updated.
+pub fn register_account_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    addr: String,
+    update_period: u64,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let converted_addr_bytes = decode_and_convert(addr.as_str())?;
+
+    let kv_key = KVKey {
+        path: ACCOUNT_STORE_KEY.to_string(),
+        key: Binary(create_account_key(converted_addr_bytes)),
+    };
+
+    register_kv_query(
+        deps,
+        env,
+        connection_id,
+        zone_id,
+        vec![kv_key],
+        update_period,
+    )
+}
+
+/// Registers an Interchain Query to get transfer events to a recipient on a remote chain.
+///
+/// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+/// * **zone_id** is used to identify the chain of interest;
+/// * **recipient** is an address of an account on remote chain for which you want to get list of transfer transactions;
+/// * **update_period** is used to say how often the query must be updated.
+/// * **min_height** is used to set min height for query (by default = 0).
+pub fn register_transfers_query(
+    deps: DepsMut<InterchainQueries>,
+    env: Env,
+    connection_id: String,
+    zone_id: String,
+    recipient: String,
+    update_period: u64,
+    min_height: Option<u64>,
+) -> NeutronResult<Response<NeutronMsg>> {
+    let mut filter = TxFilter::new().eq(RECIPIENT_FIELD, recipient)?;
+    if let Some(min_height) = min_height {
+        filter = filter.gte(HEIGHT_FIELD, min_height)?;
+    }
+
+    register_tx_query(deps, env, connection_id, zone_id, filter, update_period)
+}
+
+/// Makes the Interchain Query registration messages of **response** submessages replying with **reply_id**
+/// on success, so the identifier of the registered query can be read with **handle_register_query_reply**.
+///
+/// This is the way to get the identifier of a query registered with any **register_*_query** helper:
+/// the helpers return plain messages, and the caller which needs the identifier wraps the response
+/// with its own reply id. Other messages of **response** are left untouched.
+/// ```rust ignore
+/// // execute
+/// let response = with_reply_id(
+///     register_balance_query(deps, env, connection_id, zone_id, addr, denom, update_period)?,
+///     REGISTER_BALANCE_QUERY_REPLY_ID,
+/// );
+///
+/// // reply
+/// match msg.id {
+///     REGISTER_BALANCE_QUERY_REPLY_ID => {
+///         let query_id = handle_register_query_reply(msg)?;
+///         // map query_id to the contract records
+///     }
+///     _ => ...
+/// }
+/// ```
+pub fn with_reply_id(mut response: Response<NeutronMsg>, reply_id: u64) -> Response<NeutronMsg> {
+    for sub_msg in response.messages.iter_mut() {
+        if let CosmosMsg::Custom(NeutronMsg::RegisterInterchainQuery { .. }) = sub_msg.msg {
+            sub_msg.id = reply_id;
+            sub_msg.reply_on = ReplyOn::Success;
+        }
+    }
+
+    response
+}
+
+/// Decodes **MsgRegisterInterchainQueryResponse** from the reply to the registration message
+/// sent with **with_reply_id** and returns the identifier of the registered Interchain Query
+pub fn handle_register_query_reply(reply: Reply) -> NeutronResult<u64> {
+    let response: MsgRegisterInterchainQueryResponse = decode_message_response(reply)?;
+
+    Ok(response.id)
+}
+
 /// Updates a registered Interchain Query.
 /// Only the owner of the query can execute this message.
 ///
@@ -238,3 +918,54 @@ pub fn remove_interchain_query(query_id: u64) -> NeutronResult<Response<NeutronM
         attr("query_id", query_id.to_string()),
     ]))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::bindings::msg::{MsgRegisterInterchainQueryResponse, NeutronMsg};
+    use crate::interchain_queries::register_queries::{handle_register_query_reply, with_reply_id};
+    use crate::interchain_queries::types::QueryType;
+    use cosmwasm_std::{to_binary, Reply, Response, SubMsg, SubMsgResponse, SubMsgResult};
+
+    #[test]
+    fn test_register_query_reply() {
+        let register_msg = NeutronMsg::register_interchain_query(
+            QueryType::KV.into(),
+            vec![],
+            String::new(),
+            "zone".to_string(),
+            "connection-0".to_string(),
+            10,
+        );
+        let remove_msg = NeutronMsg::remove_interchain_query(1);
+        let response = with_reply_id(
+            Response::new()
+                .add_message(register_msg.clone())
+                .add_message(remove_msg.clone()),
+            7,
+        );
+
+        // only the registration message replies
+        assert_eq!(
+            response.messages,
+            vec![
+                SubMsg::reply_on_success(register_msg, 7),
+                SubMsg::new(remove_msg)
+            ]
+        );
+
+        let reply = Reply {
+            id: 7,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(to_binary(&MsgRegisterInterchainQueryResponse { id: 42 }).unwrap()),
+            }),
+        };
+        assert_eq!(handle_register_query_reply(reply).unwrap(), 42);
+
+        let reply = Reply {
+            id: 7,
+            result: SubMsgResult::Err("out of funds".to_string()),
+        };
+        assert!(handle_register_query_reply(reply).is_err());
+    }
+}
@@ -1,13 +1,42 @@
-use crate::bindings::types::StorageValue;
+use crate::bindings::types::{KVKey, ProtobufAny, StorageValue};
 use crate::errors::error::NeutronResult;
+use crate::interchain_queries::helpers::{
+    check_storage_prefix, consensus_address_from_pubkey, create_params_store_key,
+    create_validator_historical_rewards_key, decode_and_convert, decode_ibc_denom_hash,
+    decode_length_prefixed, get_ibc_denom,
+};
+use crate::interchain_txs::types::proto_timestamp;
 use crate::NeutronError;
-use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
-use cosmos_sdk_proto::cosmos::staking::v1beta1::{Delegation, Validator};
-use cosmwasm_std::{from_binary, Addr, Coin, Decimal, Uint128};
+use cosmos_sdk_proto::cosmos::auth::v1beta1::{BaseAccount, ModuleAccount};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::Metadata;
+use cosmos_sdk_proto::cosmos::base::v1beta1::{Coin as CosmosCoin, DecCoin};
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
+    DelegatorStartingInfo, ValidatorCurrentRewards, ValidatorHistoricalRewards, ValidatorSlashEvent,
+};
+use cosmos_sdk_proto::cosmos::gov::v1beta1::{Proposal, Vote};
+use cosmos_sdk_proto::cosmos::slashing::v1beta1::ValidatorSigningInfo as CosmosValidatorSigningInfo;
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+    Delegation, Redelegation as CosmosRedelegation,
+    UnbondingDelegation as CosmosUnbondingDelegation, Validator,
+};
+use cosmos_sdk_proto::cosmos::vesting::v1beta1::{
+    BaseVestingAccount, ContinuousVestingAccount, DelayedVestingAccount, PeriodicVestingAccount,
+    PermanentLockedAccount,
+};
+use cosmwasm_std::{
+    from_binary, Addr, Binary, Coin, Decimal, DecimalRangeExceeded, StdError, Timestamp, Uint128,
+    Uint512,
+};
+use osmosis_std::types::cosmos::base::v1beta1::Coin as OsmosisCoin;
+use osmosis_std::types::osmosis::gamm::poolmodels::stableswap::v1beta1::Pool as OsmosisStableswapPool;
+use osmosis_std::types::osmosis::gamm::v1beta1::Pool as OsmosisBalancerPool;
 use prost::Message as ProstMessage;
 use schemars::JsonSchema;
-use schemars::_serde_json::Value;
+use schemars::_serde_json::to_string;
+use serde::de::{self, DeserializeOwned, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 use std::ops::Div;
 use std::str::FromStr;
 
@@ -24,23 +53,140 @@ pub const COSMOS_SDK_TRANSFER_MSG_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
 /// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/types/key.go#L27
 pub const BALANCES_PREFIX: u8 = 0x02;
 
+/// Key for total supply of tokens in the **bank** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/types/key.go#L28
+pub const SUPPLY_PREFIX: u8 = 0x00;
+
+/// Key for denom metadata in the **bank** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/types/key.go#L29
+pub const DENOM_METADATA_PREFIX: u8 = 0x01;
+
 /// Key for delegations in the **staking** module's storage
 /// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go#L39
 pub const DELEGATION_KEY: u8 = 0x31;
 
+/// Key for unbonding delegations in the **staking** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub const UNBONDING_DELEGATION_KEY: u8 = 0x32;
+
+/// Key for redelegations in the **staking** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go
+pub const REDELEGATION_KEY: u8 = 0x34;
+
 /// Key for validators in the **staking** module's storage
 /// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/keys.go#L35
 pub const VALIDATORS_KEY: u8 = 0x21;
 
+/// Key for delegator starting info in the **distribution** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub const DELEGATOR_STARTING_INFO_PREFIX: u8 = 0x04;
+
+/// Key for validator historical rewards in the **distribution** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub const VALIDATOR_HISTORICAL_REWARDS_PREFIX: u8 = 0x05;
+
+/// Key for validator current rewards in the **distribution** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub const VALIDATOR_CURRENT_REWARDS_PREFIX: u8 = 0x06;
+
+/// Key for validator slash events in the **distribution** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/types/keys.go
+pub const VALIDATOR_SLASH_EVENT_PREFIX: u8 = 0x08;
+
+/// Key for proposals in the **gov** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/gov/types/keys.go
+pub const PROPOSALS_KEY_PREFIX: u8 = 0x00;
+
+/// Key for votes in the **gov** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/gov/types/keys.go
+pub const VOTES_KEY_PREFIX: u8 = 0x20;
+
+/// Key for accounts in the **auth** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/auth/types/keys.go
+pub const ACCOUNT_ADDRESS_PREFIX: u8 = 0x01;
+
+/// Key for validators signing info in the **slashing** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/slashing/types/keys.go
+pub const VALIDATOR_SIGNING_INFO_KEY_PREFIX: u8 = 0x01;
+
+/// Protobuf type url of Cosmos-SDK ed25519 public key which is used as a validator consensus key
+pub const ED25519_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.ed25519.PubKey";
+
+/// Length of Tendermint consensus address
+pub const CONSENSUS_ADDR_LEN: usize = 20;
+
+/// Key for contracts state in the **wasm** module's storage
+/// https://github.com/CosmWasm/wasmd/blob/v0.27.0/x/wasm/types/keys.go
+pub const WASM_CONTRACT_STORE_PREFIX: u8 = 0x03;
+
+/// Key for pools in the **gamm** module's storage of Osmosis
+/// https://github.com/osmosis-labs/osmosis/blob/v12.0.0/x/gamm/types/key.go
+pub const GAMM_POOLS_KEY_PREFIX: u8 = 0x02;
+
+/// Protobuf type url of Osmosis balancer pool
+pub const GAMM_BALANCER_POOL_TYPE_URL: &str = "/osmosis.gamm.v1beta1.Pool";
+
+/// Protobuf type url of Osmosis stableswap pool
+pub const GAMM_STABLESWAP_POOL_TYPE_URL: &str = "/osmosis.gamm.poolmodels.stableswap.v1beta1.Pool";
+
+/// Key for denom traces in the **transfer** module's storage of ibc-go
+/// https://github.com/cosmos/ibc-go/blob/v3.0.0/modules/apps/transfer/types/keys.go
+pub const DENOM_TRACE_KEY_PREFIX: u8 = 0x02;
+
+/// Prefix of denoms of tokens transferred over IBC
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// Length of the hash in IBC denom
+pub const IBC_DENOM_HASH_LEN: usize = 32;
+
+/// Key for grants in the **authz** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/authz/keeper/keys.go
+pub const AUTHZ_GRANT_KEY_PREFIX: u8 = 0x01;
+
+/// Key for fee allowances in the **feegrant** module's storage
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/feegrant/key.go
+pub const FEE_ALLOWANCE_KEY_PREFIX: u8 = 0x00;
+
 /// Maximum length of address
 pub const MAX_ADDR_LEN: usize = 255;
 
+/// Maximum length of cw-storage-plus namespace
+pub const MAX_CW_STORAGE_NAMESPACE_LEN: usize = 0xFFFF;
+
 /// Name of the standard **bank** Cosmos-SDK module
 pub const BANK_STORE_KEY: &str = "bank";
 
 /// Name of the standard **staking** Cosmos-SDK module
 pub const STAKING_STORE_KEY: &str = "staking";
 
+/// Name of the standard **distribution** Cosmos-SDK module
+pub const DISTRIBUTION_STORE_KEY: &str = "distribution";
+
+/// Name of the standard **gov** Cosmos-SDK module
+pub const GOV_STORE_KEY: &str = "gov";
+
+/// Name of the standard **slashing** Cosmos-SDK module
+pub const SLASHING_STORE_KEY: &str = "slashing";
+
+/// Name of the store of the standard **auth** Cosmos-SDK module
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/auth/types/keys.go
+pub const ACCOUNT_STORE_KEY: &str = "acc";
+
+/// Name of the store of the **wasm** module
+pub const WASM_STORE_KEY: &str = "wasm";
+
+/// Name of the **gamm** module of Osmosis
+pub const GAMM_STORE_KEY: &str = "gamm";
+
+/// Name of the standard **authz** Cosmos-SDK module
+pub const AUTHZ_STORE_KEY: &str = "authz";
+
+/// Name of the standard **feegrant** Cosmos-SDK module
+pub const FEEGRANT_STORE_KEY: &str = "feegrant";
+
+/// Name of the **transfer** module of ibc-go
+pub const TRANSFER_STORE_KEY: &str = "transfer";
+
 /// Key for bond denomination param of Cosmos-SDK staking module
 /// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/types/params.go#L39
 pub const KEY_BOND_DENOM: &str = "BondDenom";
@@ -54,7 +200,7 @@ pub const PARAMS_STORE_DELIMITER: &str = "/";
 pub const RECIPIENT_FIELD: &str = "transfer.recipient";
 pub const HEIGHT_FIELD: &str = "tx.height";
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum TransactionFilterOp {
     Eq,
     Lt,
@@ -63,10 +209,54 @@ pub enum TransactionFilterOp {
     Gte,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// Value of a transactions filter condition, **Int** values are encoded as JSON numbers
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum TransactionFilterValue {
     String(String),
-    Int(u128),
+    Int(u64),
+}
+
+impl From<String> for TransactionFilterValue {
+    fn from(v: String) -> Self {
+        TransactionFilterValue::String(v)
+    }
+}
+
+impl From<&str> for TransactionFilterValue {
+    fn from(v: &str) -> Self {
+        TransactionFilterValue::String(v.to_string())
+    }
+}
+
+impl From<u64> for TransactionFilterValue {
+    fn from(v: u64) -> Self {
+        TransactionFilterValue::Int(v)
+    }
+}
+
+/// Visits a number or a string of the transactions filter value
+struct TransactionFilterValueVisitor;
+
+impl<'de> Visitor<'de> for TransactionFilterValueVisitor {
+    type Value = TransactionFilterValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("unsigned integer or string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(TransactionFilterValue::Int(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map(TransactionFilterValue::Int)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(TransactionFilterValue::String(v.to_string()))
+    }
 }
 
 impl<'de> Deserialize<'de> for TransactionFilterValue {
@@ -74,18 +264,7 @@ impl<'de> Deserialize<'de> for TransactionFilterValue {
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Error;
-
-        let v = Value::deserialize(deserializer)?;
-        let n = v.as_u64();
-        if let Some(n) = n {
-            Ok(Self::Int(n.into()))
-        } else {
-            let n = v
-                .as_str()
-                .ok_or_else(|| D::Error::custom("Value must be number or string"))?;
-            Ok(Self::String(n.to_string()))
-        }
+        deserializer.deserialize_any(TransactionFilterValueVisitor)
     }
 }
 
@@ -94,20 +273,136 @@ impl Serialize for TransactionFilterValue {
     where
         S: Serializer,
     {
-        match &*self {
+        match self {
             TransactionFilterValue::String(v) => serializer.serialize_str(v),
-            TransactionFilterValue::Int(v) => serializer.serialize_u128(*v),
+            TransactionFilterValue::Int(v) => serializer.serialize_u64(*v),
         }
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct TransactionFilterItem {
     pub field: String,
     pub op: TransactionFilterOp,
     pub value: TransactionFilterValue,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Debug)]
+#[serde(transparent)]
+/// Transactions filter of a TX Interchain Query, a list of conditions on the events of the transactions.
+/// All the conditions must be met for a transaction to be submitted:
+/// ```rust ignore
+/// let filter = TxFilter::new()
+///     .eq("transfer.recipient", recipient)?
+///     .gte("tx.height", 1000u64)?;
+/// ```
+pub struct TxFilter(Vec<TransactionFilterItem>);
+
+impl TxFilter {
+    /// Creates an empty filter
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a condition **field** **op** **value**, e.g. `message.action = "delegate"`.
+    /// **field** is an event attribute in the form of **{event_type}.{attribute_key}**
+    pub fn condition(
+        mut self,
+        field: &str,
+        op: TransactionFilterOp,
+        value: impl Into<TransactionFilterValue>,
+    ) -> NeutronResult<Self> {
+        validate_tx_filter_field(field)?;
+
+        let value = value.into();
+        // string values are single quoted in the transactions search query
+        if let TransactionFilterValue::String(v) = &value {
+            if v.contains('\'') {
+                return Err(NeutronError::InvalidTransactionsFilter(format!(
+                    "value of {} can't contain single quotes",
+                    field
+                )));
+            }
+        }
+
+        self.0.push(TransactionFilterItem {
+            field: field.to_string(),
+            op,
+            value,
+        });
+
+        Ok(self)
+    }
+
+    /// Adds a condition **field** = **value**
+    pub fn eq(self, field: &str, value: impl Into<TransactionFilterValue>) -> NeutronResult<Self> {
+        self.condition(field, TransactionFilterOp::Eq, value)
+    }
+
+    /// Adds a condition **field** < **value**
+    pub fn lt(self, field: &str, value: impl Into<TransactionFilterValue>) -> NeutronResult<Self> {
+        self.condition(field, TransactionFilterOp::Lt, value)
+    }
+
+    /// Adds a condition **field** > **value**
+    pub fn gt(self, field: &str, value: impl Into<TransactionFilterValue>) -> NeutronResult<Self> {
+        self.condition(field, TransactionFilterOp::Gt, value)
+    }
+
+    /// Adds a condition **field** <= **value**
+    pub fn lte(self, field: &str, value: impl Into<TransactionFilterValue>) -> NeutronResult<Self> {
+        self.condition(field, TransactionFilterOp::Lte, value)
+    }
+
+    /// Adds a condition **field** >= **value**
+    pub fn gte(self, field: &str, value: impl Into<TransactionFilterValue>) -> NeutronResult<Self> {
+        self.condition(field, TransactionFilterOp::Gte, value)
+    }
+
+    /// Returns the conditions of the filter
+    pub fn items(&self) -> &[TransactionFilterItem] {
+        &self.0
+    }
+
+    /// Returns the number of conditions of the filter
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns **true** if the filter has no conditions
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Encodes the filter into JSON to register a TX Interchain Query with
+    pub fn to_json(&self) -> NeutronResult<String> {
+        to_string(&self.0).map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))
+    }
+}
+
+/// Checks that **field** of a transactions filter is an event attribute like **transfer.recipient**:
+/// non-empty event type and attribute key of letters, digits, underscores and dashes separated by a dot
+fn validate_tx_filter_field(field: &str) -> NeutronResult<()> {
+    let is_valid_part = |part: &str| {
+        !part.is_empty()
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+    };
+
+    match field.split_once('.') {
+        Some((event_type, attribute_key))
+            if is_valid_part(event_type) && attribute_key.split('.').all(is_valid_part) =>
+        {
+            Ok(())
+        }
+        _ => Err(NeutronError::InvalidTransactionsFilter(format!(
+            "invalid field {:?}, expected {{event_type}}.{{attribute_key}}",
+            field
+        ))),
+    }
+}
+
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
 /// Describes possible interchain query types
 pub enum QueryType {
@@ -161,11 +456,174 @@ pub type AddressBytes = Vec<u8>;
 /// ```
 ///
 /// Anyone can implement `KVReconstruct` for any type and use `query_kv_result` without any problems.
+/// For a struct of protobuf or JSON encoded values it can be derived with `#[derive(KVReconstruct)]`:
+/// ```rust
+/// use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+/// use cosmwasm_std::Binary;
+/// use neutron_sdk::bindings::types::StorageValue;
+/// use neutron_sdk::interchain_queries::helpers::{
+///     create_account_denom_balance_key, create_gamm_pool_key, decode_and_convert,
+/// };
+/// use neutron_sdk::interchain_queries::types::KVReconstruct;
+/// use neutron_sdk::NeutronResult;
+///
+/// #[derive(KVReconstruct)]
+/// #[kv(keys(pool_id: u64, addr: Vec<u8>))]
+/// struct PoolWithBalance {
+///     #[kv(path = "gamm", key = create_gamm_pool_key(*pool_id))]
+///     pool: prost_types::Any,
+///     #[kv(path = "bank", key = create_account_denom_balance_key(addr, "uosmo")?)]
+///     balance: Option<Coin>,
+/// }
+///
+/// # fn main() -> NeutronResult<()> {
+/// let addr = decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs")?;
+/// let keys = PoolWithBalanceKeys { pool_id: 1, addr };
+///
+/// // the query is registered with keys.kv_keys()?, and its result must be read by the same keys
+/// let kv_results: Vec<StorageValue> = keys
+///     .kv_keys()?
+///     .into_iter()
+///     .map(|key| StorageValue {
+///         storage_prefix: key.path,
+///         key: key.key,
+///         value: Binary::default(),
+///     })
+///     .collect();
+///
+/// let pool_with_balance = keys.reconstruct(&kv_results)?;
+/// assert_eq!(pool_with_balance.pool, prost_types::Any::default());
+/// assert_eq!(pool_with_balance.balance, None);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Reconstructs compose: `Option<T>`, `Vec<T>` and tuples of reconstructs are reconstructs too,
+/// so one query can fetch values of different types and split them by **kv_count**:
+/// ```rust ignore
+/// let (account, balances): (AccountInfo, Balances) = query_kv_result(deps, query_id)?;
+/// ```
 pub trait KVReconstruct: Sized {
     /// Reconstructs this value from the slice of **StorageValue**'s.
     fn reconstruct(kvs: &[StorageValue]) -> NeutronResult<Self>;
+
+    /// Returns the number of **StorageValue**'s this value is reconstructed from,
+    /// **None** means the value consumes all the storage values it's given
+    fn kv_count() -> Option<usize> {
+        None
+    }
+
+    /// Reconstructs this value from the beginning of the slice of **StorageValue**'s.
+    /// Returns the value and the number of consumed storage values
+    fn reconstruct_prefix(kvs: &[StorageValue]) -> NeutronResult<(Self, usize)> {
+        let count = Self::kv_count().unwrap_or(kvs.len());
+        if kvs.len() < count {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected at least {} storage values, got {}",
+                count,
+                kvs.len()
+            )));
+        }
+
+        Ok((Self::reconstruct(&kvs[..count])?, count))
+    }
+}
+
+pub use neutron_sdk_derive::KVReconstruct;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A single **StorageValue** as it's returned by the Interchain Query, without any decoding
+pub struct RawKv(pub StorageValue);
+
+impl KVReconstruct for RawKv {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<RawKv> {
+        if storage_values.len() != 1 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected one storage value, got {}",
+                storage_values.len()
+            )));
+        }
+
+        Ok(RawKv(storage_values[0].clone()))
+    }
+
+    fn kv_count() -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Reconstructs **None** if all the storage values are empty, i.e. don't exist on remote chain
+impl<T: KVReconstruct> KVReconstruct for Option<T> {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Option<T>> {
+        if storage_values.iter().all(|kv| kv.value.is_empty()) {
+            return Ok(None);
+        }
+
+        Ok(Some(T::reconstruct(storage_values)?))
+    }
+
+    fn kv_count() -> Option<usize> {
+        T::kv_count()
+    }
+}
+
+/// Reconstructs an element from every **T::kv_count()** storage values (from every value if it's not set)
+impl<T: KVReconstruct> KVReconstruct for Vec<T> {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Vec<T>> {
+        let chunk_size = T::kv_count().unwrap_or(1).max(1);
+        if storage_values.len() % chunk_size != 0 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected a multiple of {} storage values, got {}",
+                chunk_size,
+                storage_values.len()
+            )));
+        }
+
+        storage_values
+            .chunks(chunk_size)
+            .map(T::reconstruct)
+            .collect()
+    }
+}
+
+/// Implements **KVReconstruct** for a tuple of reconstructs, the elements consume storage values one by one,
+/// so only the last one may consume an unknown number of values
+macro_rules! impl_kv_reconstruct_for_tuple {
+    ($($t:ident),+) => {
+        impl<$($t: KVReconstruct),+> KVReconstruct for ($($t,)+) {
+            fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Self> {
+                let mut consumed: usize = 0;
+                let value = ($({
+                    let (element, count) = $t::reconstruct_prefix(&storage_values[consumed..])?;
+                    consumed += count;
+                    element
+                },)+);
+
+                if consumed != storage_values.len() {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "expected {} storage values, got {}",
+                        consumed,
+                        storage_values.len()
+                    )));
+                }
+
+                Ok(value)
+            }
+
+            fn kv_count() -> Option<usize> {
+                Some(0 $(+ $t::kv_count()?)+)
+            }
+        }
+    };
 }
 
+impl_kv_reconstruct_for_tuple!(A);
+impl_kv_reconstruct_for_tuple!(A, B);
+impl_kv_reconstruct_for_tuple!(A, B, C);
+impl_kv_reconstruct_for_tuple!(A, B, C, D);
+impl_kv_reconstruct_for_tuple!(A, B, C, D, E);
+impl_kv_reconstruct_for_tuple!(A, B, C, D, E, F);
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Balance Interchain Query**.
 /// Contains coins that are held by some account on remote chain.
@@ -175,12 +633,63 @@ pub struct Balances {
 
 impl KVReconstruct for Balances {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Balances> {
-        let mut coins: Vec<Coin> = vec![];
+        let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
+        let mut account: Option<&[u8]> = None;
 
         for kv in storage_values {
+            check_storage_prefix(kv, BANK_STORE_KEY)?;
+
+            // the key is BALANCES_PREFIX | len(addr) | addr | denom
+            let (addr, denom) = match kv.key.as_slice().split_first() {
+                Some((&BALANCES_PREFIX, rest)) => decode_length_prefixed(rest)?,
+                _ => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "unexpected balance key".into(),
+                    ))
+                }
+            };
+            let denom = String::from_utf8(denom.to_vec()).map_err(|_| {
+                NeutronError::InvalidQueryResultFormat("invalid denom in balance key".into())
+            })?;
+            if denom.is_empty() {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "empty denom in balance key".into(),
+                ));
+            }
+
+            // all the balances must belong to the same account
+            match account {
+                None => account = Some(addr),
+                Some(account) if account != addr => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "balances of different accounts".into(),
+                    ))
+                }
+                _ => {}
+            }
+
+            if coins.iter().any(|coin| coin.denom == denom) {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "duplicate balance of {}",
+                    denom
+                )));
+            }
+
+            // the balance doesn't exist on remote chain, i.e. it's zero
+            if kv.value.is_empty() {
+                coins.push(Coin::new(0, denom));
+                continue;
+            }
+
             let balance: CosmosCoin = CosmosCoin::decode(kv.value.as_slice())?;
+            if balance.denom != denom {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "balance of {} is stored under the key of {}",
+                    balance.denom, denom
+                )));
+            }
             let amount = Uint128::from_str(balance.amount.as_str())?;
-            coins.push(Coin::new(amount.u128(), balance.denom));
+            coins.push(Coin::new(amount.u128(), denom));
         }
 
         Ok(Balances { coins })
@@ -196,10 +705,17 @@ pub struct Delegations {
 
 impl KVReconstruct for Delegations {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Delegations> {
-        let mut delegations: Vec<cosmwasm_std::Delegation> = vec![];
-
         // first StorageValue is denom
-        if storage_values[0].value.is_empty() {
+        let (denom_kv, storage_values) = storage_values
+            .split_first()
+            .ok_or_else(|| NeutronError::InvalidQueryResultFormat("denom is missing".into()))?;
+        check_storage_prefix(denom_kv, PARAMS_STORE_KEY)?;
+        if denom_kv.key.as_slice() != create_params_store_key(STAKING_STORE_KEY, KEY_BOND_DENOM) {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "unexpected denom key".into(),
+            ));
+        }
+        if denom_kv.value.is_empty() {
             // Incoming denom cannot be empty, it should always be configured on chain.
             // If we receive empty denom, that means incoming data structure is corrupted
             // and we cannot build `cosmwasm_std::Delegation`'s using this data.
@@ -207,310 +723,5475 @@ impl KVReconstruct for Delegations {
                 "denom is empty".into(),
             ));
         }
-        let denom: String = from_binary(&storage_values[0].value)?;
+        let denom: String = from_binary(&denom_kv.value)?;
+
+        // the rest are delegations and validators, they are matched by validator address from the keys,
+        // delegations keep the order they are registered in
+        let mut delegator: Option<&[u8]> = None;
+        let mut delegation_values: Vec<(&[u8], &StorageValue)> = vec![];
+        let mut validator_values: BTreeMap<&[u8], &StorageValue> = BTreeMap::new();
+
+        for kv in storage_values {
+            check_storage_prefix(kv, STAKING_STORE_KEY)?;
+
+            match kv.key.as_slice().split_first() {
+                // the key is DELEGATION_KEY | len(delegator) | delegator | len(validator) | validator
+                Some((&DELEGATION_KEY, rest)) => {
+                    let (del_addr, rest) = decode_length_prefixed(rest)?;
+                    let (val_addr, rest) = decode_length_prefixed(rest)?;
+                    if !rest.is_empty() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "invalid delegation key".into(),
+                        ));
+                    }
+
+                    match delegator {
+                        None => delegator = Some(del_addr),
+                        Some(delegator) if delegator != del_addr => {
+                            return Err(NeutronError::InvalidQueryResultFormat(
+                                "delegations of different delegators".into(),
+                            ))
+                        }
+                        _ => {}
+                    }
+
+                    if delegation_values.iter().any(|(addr, _)| *addr == val_addr) {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "duplicate delegation".into(),
+                        ));
+                    }
+                    delegation_values.push((val_addr, kv));
+                }
+                // the key is VALIDATORS_KEY | len(validator) | validator
+                Some((&VALIDATORS_KEY, rest)) => {
+                    let (val_addr, rest) = decode_length_prefixed(rest)?;
+                    if !rest.is_empty() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "invalid validator key".into(),
+                        ));
+                    }
+
+                    if validator_values.insert(val_addr, kv).is_some() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "duplicate validator".into(),
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "unexpected delegations query key".into(),
+                    ))
+                }
+            }
+        }
+
+        // every delegation is registered along with its validator
+        if validator_values.len() != delegation_values.len() {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "validator is missing".into(),
+            ));
+        }
+
+        let mut delegations: Vec<cosmwasm_std::Delegation> =
+            Vec::with_capacity(delegation_values.len());
 
-        // the rest are delegations and validators alternately
-        for chunk in storage_values[1..].chunks(2) {
-            if chunk[0].value.is_empty() {
+        for (val_addr, delegation_kv) in delegation_values {
+            let validator_kv = validator_values.get(val_addr).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat("validator is missing".into())
+            })?;
+
+            if delegation_kv.value.is_empty() {
                 // Incoming delegation can actually be empty, this just means that delegation
                 // is not present on remote chain, which is to be expected. So, if it doesn't
-                // exist, we can safely skip this and following chunk.
+                // exist, we can safely skip it and its validator.
                 continue;
             }
-            let delegation_sdk: Delegation = Delegation::decode(chunk[0].value.as_slice())?;
-
-            let mut delegation_std = cosmwasm_std::Delegation {
-                delegator: Addr::unchecked(delegation_sdk.delegator_address.as_str()),
-                validator: delegation_sdk.validator_address,
-                amount: Default::default(),
-            };
+            let delegation_sdk: Delegation = Delegation::decode(delegation_kv.value.as_slice())?;
 
-            if chunk[1].value.is_empty() {
+            if validator_kv.value.is_empty() {
                 // At this point, incoming validator cannot be empty, that would be invalid,
                 // because delegation is already defined, so, building `cosmwasm_std::Delegation`
-                // from this data is impossible, incoming data is corrupted.post
+                // from this data is impossible, incoming data is corrupted.
                 return Err(NeutronError::InvalidQueryResultFormat(
                     "validator is empty".into(),
                 ));
             }
-            let validator: Validator = Validator::decode(chunk[1].value.as_slice())?;
-
-            let delegation_shares =
-                Decimal::from_atomics(Uint128::from_str(&delegation_sdk.shares)?, DECIMAL_PLACES)?;
-
-            let delegator_shares = Decimal::from_atomics(
-                Uint128::from_str(&validator.delegator_shares)?,
-                DECIMAL_PLACES,
-            )?;
-
-            let validator_tokens = Decimal::from_atomics(Uint128::from_str(&validator.tokens)?, 0)?;
+            let validator: Validator = Validator::decode(validator_kv.value.as_slice())?;
 
             // https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/staking/keeper/querier.go#L463
             // delegated_tokens = quotient(delegation.shares * validator.tokens / validator.total_shares);
-            let delegated_tokens = delegation_shares
-                .checked_mul(validator_tokens)?
-                .div(delegator_shares)
-                .atomics()
-                .u128()
-                .div(DECIMAL_FRACTIONAL);
-
-            delegation_std.amount = Coin::new(delegated_tokens, &denom);
+            // shares of both delegation and validator are decimals with the same precision,
+            // so their atomics ratio is the ratio of the shares
+            let delegated_tokens = Uint128::from_str(&delegation_sdk.shares)?
+                .checked_multiply_ratio(
+                    Uint128::from_str(&validator.tokens)?,
+                    Uint128::from_str(&validator.delegator_shares)?,
+                )
+                .map_err(|e| {
+                    NeutronError::InvalidQueryResultFormat(format!(
+                        "invalid validator shares: {}",
+                        e
+                    ))
+                })?;
 
-            delegations.push(delegation_std);
+            delegations.push(cosmwasm_std::Delegation {
+                delegator: Addr::unchecked(delegation_sdk.delegator_address.as_str()),
+                validator: delegation_sdk.validator_address,
+                amount: Coin::new(delegated_tokens.u128(), &denom),
+            });
         }
 
         Ok(Delegations { delegations })
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::bindings::types::StorageValue;
-    use crate::interchain_queries::helpers::{
-        create_account_denom_balance_key, create_delegation_key, create_params_store_key,
-        create_validator_key, decode_and_convert,
-    };
-    use crate::interchain_queries::types::{
-        Balances, Delegations, KVReconstruct, KEY_BOND_DENOM, STAKING_STORE_KEY,
-    };
-    use crate::{NeutronError, NeutronResult};
-    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
-    use cosmos_sdk_proto::cosmos::staking::v1beta1::{Delegation, Validator};
-    use cosmwasm_std::{
-        to_binary, Addr, Binary, Coin as StdCoin, Delegation as StdDelegation, Uint128,
-    };
-    use prost::Message as ProstMessage;
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Bank Total Supply Interchain Query**.
+/// Contains total supply of the requested denoms on remote chain.
+pub struct TotalSupply {
+    pub coins: Vec<Coin>,
+}
 
-    #[test]
-    fn test_balance_reconstruct() {
-        struct TestCase {
-            addr: String,
-            coins: Vec<(String, Uint128)>,
+impl KVReconstruct for TotalSupply {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<TotalSupply> {
+        let mut coins: Vec<Coin> = vec![];
+
+        for kv in storage_values {
+            // the key is SUPPLY_PREFIX | denom
+            let denom = match kv.key.as_slice().split_first() {
+                Some((&SUPPLY_PREFIX, denom)) => {
+                    String::from_utf8(denom.to_vec()).map_err(|_| {
+                        NeutronError::InvalidQueryResultFormat("supply denom is not utf8".into())
+                    })?
+                }
+                _ => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "invalid total supply key".into(),
+                    ))
+                }
+            };
+
+            // the supply of a denom which doesn't exist on remote chain is zero
+            let amount = if kv.value.is_empty() {
+                Uint128::zero()
+            } else {
+                // the value is a text representation of sdk.Int
+                let amount = std::str::from_utf8(kv.value.as_slice()).map_err(|_| {
+                    NeutronError::InvalidQueryResultFormat("supply amount is not utf8".into())
+                })?;
+                Uint128::from_str(amount)?
+            };
+
+            coins.push(Coin::new(amount.u128(), denom));
         }
-        let test_cases: Vec<TestCase> = vec![
-            TestCase {
-                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
-                coins: vec![("uosmo".to_string(), Uint128::from(100u128))],
-            },
-            TestCase {
-                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
-                coins: vec![
-                    ("uosmo".to_string(), Uint128::from(100u128)),
-                    ("uatom".to_string(), Uint128::from(500u128)),
-                    ("uluna".to_string(), Uint128::from(80u128)),
-                ],
-            },
-            TestCase {
-                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
-                coins: vec![],
-            },
-        ];
 
-        for ts in test_cases.iter() {
-            let mut st_values: Vec<StorageValue> = vec![];
+        Ok(TotalSupply { coins })
+    }
+}
 
-            let converted_addr_bytes = decode_and_convert(ts.addr.as_str()).unwrap();
-            for coin in &ts.coins {
-                let balance_key =
-                    create_account_denom_balance_key(converted_addr_bytes.clone(), &coin.0)
-                        .unwrap();
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a unit of a denom, e.g. **uatom** with exponent 0 and **atom** with exponent 6
+pub struct DenomUnit {
+    /// **denom** is a string representation of the unit
+    pub denom: String,
 
-                let balance_amount = Coin {
-                    denom: coin.0.clone(),
-                    amount: coin.1.to_string(),
-                };
-                let s = StorageValue {
-                    storage_prefix: "".to_string(),
+    /// **exponent** is a power of 10 of the unit relative to the base denom
+    pub exponent: u32,
+
+    /// **aliases** is a list of aliases of the unit
+    pub aliases: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes metadata of a denom registered in the **bank** module on remote chain
+pub struct DenomMetadataInfo {
+    pub description: String,
+
+    /// **denom_units** is a list of units of the denom
+    pub denom_units: Vec<DenomUnit>,
+
+    /// **base** is the base denom (the unit with exponent 0)
+    pub base: String,
+
+    /// **display** is the denom which should be used to display the token
+    pub display: String,
+
+    pub name: String,
+
+    pub symbol: String,
+}
+
+impl DenomMetadataInfo {
+    /// Returns the number of decimals of the **display** denom, if the display unit is defined
+    pub fn decimals(&self) -> Option<u32> {
+        self.denom_units
+            .iter()
+            .find(|u| u.denom == self.display)
+            .map(|u| u.exponent)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Bank Denom Metadata Interchain Query**.
+/// Contains metadata of the requested denoms, denoms without metadata on remote chain are omitted.
+pub struct DenomMetadata {
+    pub metadata: Vec<DenomMetadataInfo>,
+}
+
+impl KVReconstruct for DenomMetadata {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<DenomMetadata> {
+        let mut metadata: Vec<DenomMetadataInfo> = vec![];
+
+        for kv in storage_values {
+            if kv.value.is_empty() {
+                continue;
+            }
+
+            let m: Metadata = Metadata::decode(kv.value.as_slice())?;
+            metadata.push(DenomMetadataInfo {
+                description: m.description,
+                denom_units: m
+                    .denom_units
+                    .into_iter()
+                    .map(|u| DenomUnit {
+                        denom: u.denom,
+                        exponent: u.exponent,
+                        aliases: u.aliases,
+                    })
+                    .collect(),
+                base: m.base,
+                display: m.display,
+                name: m.name,
+                symbol: m.symbol,
+            });
+        }
+
+        Ok(DenomMetadata { metadata })
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes bond status of a validator
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/staking/v1beta1/staking.proto
+pub enum BondStatus {
+    Unspecified,
+    Unbonded,
+    Unbonding,
+    Bonded,
+}
+
+impl BondStatus {
+    /// Converts protobuf representation of bond status into **BondStatus**
+    /// Returns **None** if the value is unknown
+    pub fn from_i32(status: i32) -> Option<BondStatus> {
+        match status {
+            0 => Some(BondStatus::Unspecified),
+            1 => Some(BondStatus::Unbonded),
+            2 => Some(BondStatus::Unbonding),
+            3 => Some(BondStatus::Bonded),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes description of a validator
+pub struct ValidatorDescription {
+    pub moniker: String,
+    pub identity: String,
+    pub website: String,
+    pub security_contact: String,
+    pub details: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes commission of a validator
+pub struct ValidatorCommission {
+    /// **rate** is the commission rate charged to delegators
+    pub rate: Decimal,
+
+    /// **max_rate** is the maximum commission rate which the validator can ever charge
+    pub max_rate: Decimal,
+
+    /// **max_change_rate** is the maximum daily increase of the validator commission
+    pub max_change_rate: Decimal,
+
+    /// **update_time** is the last time the commission rate was changed
+    pub update_time: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a validator on remote chain
+pub struct StakingValidator {
+    /// **operator_address** is the address of the validator's operator, bech encoded
+    pub operator_address: String,
+
+    /// **consensus_pubkey** is the consensus public key of the validator
+    pub consensus_pubkey: Option<ProtobufAny>,
+
+    /// **jailed** defines whether the validator has been jailed from bonded status or not
+    pub jailed: bool,
+
+    /// **status** is the validator status (bonded/unbonding/unbonded)
+    pub status: BondStatus,
+
+    /// **tokens** is the amount of the tokens delegated to the validator (including self-delegation)
+    pub tokens: Uint128,
+
+    /// **delegator_shares** is the total shares issued to the validator's delegators
+    pub delegator_shares: Decimal,
+
+    pub description: Option<ValidatorDescription>,
+
+    /// **unbonding_height** is the height at which the validator has begun unbonding
+    pub unbonding_height: i64,
+
+    /// **unbonding_time** is the minimum time of the validator unbonding completion
+    pub unbonding_time: Option<Timestamp>,
+
+    pub commission: Option<ValidatorCommission>,
+
+    /// **min_self_delegation** is the validator's self declared minimum self delegation
+    pub min_self_delegation: Uint128,
+}
+
+impl StakingValidator {
+    /// Returns Tendermint consensus address of the validator,
+    /// which is used to get its signing info from the **slashing** module
+    pub fn consensus_address(&self) -> NeutronResult<AddressBytes> {
+        let pubkey = self.consensus_pubkey.as_ref().ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("validator consensus pubkey is empty".into())
+        })?;
+
+        consensus_address_from_pubkey(pubkey)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Staking Validators Interchain Query**.
+/// Contains validators info, keys of validators which don't exist on remote chain are listed in **absent_keys**.
+pub struct StakingValidators {
+    pub validators: Vec<StakingValidator>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl KVReconstruct for StakingValidators {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<StakingValidators> {
+        let mut validators: Vec<StakingValidator> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut val_addrs: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is VALIDATORS_KEY | len(validator) | validator
+            let rest = strip_key_prefix(kv, STAKING_STORE_KEY, VALIDATORS_KEY, "validator")?;
+            let (val_addr, rest) = decode_length_prefixed(rest)?;
+            check_key_end(rest, "validator")?;
+
+            if !val_addrs.insert(val_addr) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate validator".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the validator doesn't exist on remote chain
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let validator: Validator = Validator::decode(kv.value.as_slice())?;
+            check_key_address(&validator.operator_address, val_addr, "validator")?;
+
+            let status = BondStatus::from_i32(validator.status).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat(format!(
+                    "invalid validator status: {}",
+                    validator.status
+                ))
+            })?;
+
+            let commission = match validator.commission {
+                Some(c) => {
+                    let rates = c.commission_rates.unwrap_or_default();
+                    Some(ValidatorCommission {
+                        rate: parse_sdk_dec(&rates.rate)?,
+                        max_rate: parse_sdk_dec(&rates.max_rate)?,
+                        max_change_rate: parse_sdk_dec(&rates.max_change_rate)?,
+                        update_time: c
+                            .update_time
+                            .map(|t| proto_timestamp(t.seconds, t.nanos))
+                            .transpose()?,
+                    })
+                }
+                None => None,
+            };
+
+            validators.push(StakingValidator {
+                operator_address: validator.operator_address,
+                consensus_pubkey: validator
+                    .consensus_pubkey
+                    .map(|k| ProtobufAny::new(k.type_url, Binary::from(k.value))),
+                jailed: validator.jailed,
+                status,
+                tokens: Uint128::from_str(&validator.tokens)?,
+                delegator_shares: parse_sdk_dec(&validator.delegator_shares)?,
+                description: validator.description.map(|d| ValidatorDescription {
+                    moniker: d.moniker,
+                    identity: d.identity,
+                    website: d.website,
+                    security_contact: d.security_contact,
+                    details: d.details,
+                }),
+                unbonding_height: validator.unbonding_height,
+                unbonding_time: validator
+                    .unbonding_time
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+                commission,
+                min_self_delegation: Uint128::from_str(&validator.min_self_delegation)?,
+            })
+        }
+
+        Ok(StakingValidators {
+            validators,
+            absent_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a single unbonding of some delegator's tokens from a validator
+pub struct UnbondingDelegationEntry {
+    /// **creation_height** is the height at which the unbonding took place
+    pub creation_height: i64,
+
+    /// **completion_time** is the time at which the unbonding will complete
+    pub completion_time: Option<Timestamp>,
+
+    /// **initial_balance** is the amount of tokens initially scheduled to be released on completion
+    pub initial_balance: Uint128,
+
+    /// **balance** is the amount of tokens to be released on completion (can be less than
+    /// **initial_balance** if the validator was slashed during unbonding)
+    pub balance: Uint128,
+}
+
+impl UnbondingDelegationEntry {
+    /// Returns **true** if the unbonding has completed by **time**,
+    /// i.e. the tokens are released to the delegator's account on remote chain
+    pub fn is_completed(&self, time: Timestamp) -> bool {
+        self.completion_time.map_or(false, |t| t <= time)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes all unbondings of some delegator's tokens from a particular validator
+pub struct UnbondingDelegation {
+    pub delegator_address: String,
+    pub validator_address: String,
+    pub entries: Vec<UnbondingDelegationEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Delegator Unbonding Delegations Interchain Query**.
+/// Contains unbonding delegations which some delegator has on remote chain,
+/// keys of validators without unbonding delegations from the delegator are listed in **absent_keys**.
+pub struct UnbondingDelegations {
+    pub unbonding_responses: Vec<UnbondingDelegation>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl UnbondingDelegations {
+    /// Returns total amount of tokens that have completed unbonding by **time**
+    pub fn completed_amount(&self, time: Timestamp) -> NeutronResult<Uint128> {
+        self.unbonding_responses
+            .iter()
+            .flat_map(|ubd| ubd.entries.iter())
+            .filter(|entry| entry.is_completed(time))
+            .try_fold(Uint128::zero(), |acc, entry| {
+                Ok(acc.checked_add(entry.balance)?)
+            })
+    }
+}
+
+impl KVReconstruct for UnbondingDelegations {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<UnbondingDelegations> {
+        let mut unbonding_responses: Vec<UnbondingDelegation> =
+            Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut delegator: Option<&[u8]> = None;
+        let mut val_addrs: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is UNBONDING_DELEGATION_KEY | len(delegator) | delegator | len(validator) | validator
+            let rest = strip_key_prefix(
+                kv,
+                STAKING_STORE_KEY,
+                UNBONDING_DELEGATION_KEY,
+                "unbonding delegation",
+            )?;
+            let (del_addr, rest) = decode_length_prefixed(rest)?;
+            let (val_addr, rest) = decode_length_prefixed(rest)?;
+            check_key_end(rest, "unbonding delegation")?;
+
+            match delegator {
+                None => delegator = Some(del_addr),
+                Some(delegator) if delegator != del_addr => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "unbonding delegations of different delegators".into(),
+                    ))
+                }
+                _ => {}
+            }
+
+            if !val_addrs.insert(val_addr) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate unbonding delegation".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the delegator has no unbonding delegations from the validator
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let ubd: CosmosUnbondingDelegation =
+                CosmosUnbondingDelegation::decode(kv.value.as_slice())?;
+            check_key_address(&ubd.delegator_address, del_addr, "delegator")?;
+            check_key_address(&ubd.validator_address, val_addr, "validator")?;
+
+            let mut entries: Vec<UnbondingDelegationEntry> = Vec::with_capacity(ubd.entries.len());
+            for entry in ubd.entries {
+                entries.push(UnbondingDelegationEntry {
+                    creation_height: entry.creation_height,
+                    completion_time: entry
+                        .completion_time
+                        .map(|t| proto_timestamp(t.seconds, t.nanos))
+                        .transpose()?,
+                    initial_balance: Uint128::from_str(&entry.initial_balance)?,
+                    balance: Uint128::from_str(&entry.balance)?,
+                })
+            }
+
+            unbonding_responses.push(UnbondingDelegation {
+                delegator_address: ubd.delegator_address,
+                validator_address: ubd.validator_address,
+                entries,
+            })
+        }
+
+        Ok(UnbondingDelegations {
+            unbonding_responses,
+            absent_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a single redelegation of some delegator's tokens between two validators
+pub struct RedelegationEntry {
+    /// **creation_height** is the height at which the redelegation took place
+    pub creation_height: i64,
+
+    /// **completion_time** is the time at which the redelegation will complete
+    pub completion_time: Option<Timestamp>,
+
+    /// **initial_balance** is the amount of tokens initially scheduled to be redelegated
+    pub initial_balance: Uint128,
+
+    /// **shares_dst** is the amount of destination validator shares created by the redelegation
+    pub shares_dst: Decimal,
+}
+
+impl RedelegationEntry {
+    /// Returns **true** if the redelegation has completed (matured) by **time**
+    pub fn is_completed(&self, time: Timestamp) -> bool {
+        self.completion_time.map_or(false, |t| t <= time)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes all redelegations of some delegator's tokens from a source validator to a destination validator
+pub struct Redelegation {
+    pub delegator_address: String,
+    pub validator_src_address: String,
+    pub validator_dst_address: String,
+    pub entries: Vec<RedelegationEntry>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Delegator Redelegations Interchain Query**.
+/// Contains redelegations which some delegator has on remote chain,
+/// keys of validators pairs without redelegations from the delegator are listed in **absent_keys**.
+pub struct Redelegations {
+    pub redelegations: Vec<Redelegation>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl Redelegations {
+    /// Returns **true** if there is a redelegation to **validator** which hasn't completed by **time**.
+    /// Remote chain rejects redelegations from such a validator until all the incoming redelegations are completed
+    pub fn has_maturing_redelegation_to(&self, validator: &str, time: Timestamp) -> bool {
+        self.redelegations
+            .iter()
+            .filter(|red| red.validator_dst_address == validator)
+            .flat_map(|red| red.entries.iter())
+            .any(|entry| !entry.is_completed(time))
+    }
+}
+
+impl KVReconstruct for Redelegations {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Redelegations> {
+        let mut redelegations: Vec<Redelegation> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut delegator: Option<&[u8]> = None;
+        let mut validators_pairs: BTreeSet<(&[u8], &[u8])> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is REDELEGATION_KEY | len(delegator) | delegator | len(src_validator) | src_validator
+            // | len(dst_validator) | dst_validator
+            let rest = strip_key_prefix(kv, STAKING_STORE_KEY, REDELEGATION_KEY, "redelegation")?;
+            let (del_addr, rest) = decode_length_prefixed(rest)?;
+            let (src_addr, rest) = decode_length_prefixed(rest)?;
+            let (dst_addr, rest) = decode_length_prefixed(rest)?;
+            check_key_end(rest, "redelegation")?;
+
+            match delegator {
+                None => delegator = Some(del_addr),
+                Some(delegator) if delegator != del_addr => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "redelegations of different delegators".into(),
+                    ))
+                }
+                _ => {}
+            }
+
+            if !validators_pairs.insert((src_addr, dst_addr)) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate redelegation".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the delegator has no redelegations between the validators
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let red: CosmosRedelegation = CosmosRedelegation::decode(kv.value.as_slice())?;
+            check_key_address(&red.delegator_address, del_addr, "delegator")?;
+            check_key_address(&red.validator_src_address, src_addr, "source validator")?;
+            check_key_address(
+                &red.validator_dst_address,
+                dst_addr,
+                "destination validator",
+            )?;
+
+            let mut entries: Vec<RedelegationEntry> = Vec::with_capacity(red.entries.len());
+            for entry in red.entries {
+                entries.push(RedelegationEntry {
+                    creation_height: entry.creation_height,
+                    completion_time: entry
+                        .completion_time
+                        .map(|t| proto_timestamp(t.seconds, t.nanos))
+                        .transpose()?,
+                    initial_balance: Uint128::from_str(&entry.initial_balance)?,
+                    shares_dst: parse_sdk_dec(&entry.shares_dst)?,
+                })
+            }
+
+            redelegations.push(Redelegation {
+                delegator_address: red.delegator_address,
+                validator_src_address: red.validator_src_address,
+                validator_dst_address: red.validator_dst_address,
+                entries,
+            })
+        }
+
+        Ok(Redelegations {
+            redelegations,
+            absent_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes rewards of a delegator accumulated for a delegation to a particular validator
+pub struct DelegationRewards {
+    pub validator_address: String,
+    pub rewards: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Identifies a slash event of a validator in the distribution store of remote chain.
+/// The event is stored by the **height** of the block it happened at (the block has **slash** event)
+/// and the validator **period** it ended
+pub struct ValidatorSlashEventKey {
+    /// **validator** is the operator address of the slashed validator
+    pub validator: String,
+
+    /// **height** is the height of the block the validator was slashed at
+    pub height: u64,
+
+    /// **period** is the validator period ended by the slash
+    pub period: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Delegator Rewards Interchain Query**.
+/// Contains rewards which some delegator can withdraw on remote chain, calculated the same way
+/// as Cosmos-SDK's **CalculateDelegationRewards** does.
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/keeper/delegation.go
+///
+/// Validator historical rewards keys required for the calculation depend on the values of other keys,
+/// so they can't be known at the moment the query is registered. Such keys are listed in **missing_keys**
+/// and must be added to the query (with `update_interchain_query`) for the rewards to be calculated.
+///
+/// Slash events of validators can't be listed with KV queries either, their keys must be added to the query
+/// as **ValidatorSlashEventKey**'s. If the current stake of a delegation shows that the validator was slashed
+/// by an event which is not in the query, the reconstruction fails instead of returning overstated rewards.
+pub struct PendingRewards {
+    /// **rewards** is a list of rewards for every delegation which rewards can be calculated
+    pub rewards: Vec<DelegationRewards>,
+
+    /// **total** is a sum of all the **rewards**
+    pub total: Vec<Coin>,
+
+    /// **missing_keys** is a list of keys which are required to calculate rewards of the rest of the delegations
+    pub missing_keys: Vec<KVKey>,
+}
+
+impl KVReconstruct for PendingRewards {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<PendingRewards> {
+        // all the structures are indexed by validator (and delegator) address bytes taken from the keys,
+        // starting infos and delegations don't exist if there is no delegation to the validator
+        let mut starting_infos: Vec<(AddressBytes, AddressBytes, Option<DelegatorStartingInfo>)> =
+            vec![];
+        let mut validators: BTreeMap<AddressBytes, Validator> = BTreeMap::new();
+        let mut delegations: BTreeMap<(AddressBytes, AddressBytes), Option<Delegation>> =
+            BTreeMap::new();
+        let mut current_rewards: BTreeMap<AddressBytes, ValidatorCurrentRewards> = BTreeMap::new();
+        let mut historical_rewards: BTreeMap<(AddressBytes, u64), Binary> = BTreeMap::new();
+        let mut slash_events: BTreeMap<AddressBytes, Vec<(u64, ValidatorSlashEvent)>> =
+            BTreeMap::new();
+
+        for kv in storage_values {
+            let (prefix, rest) = kv.key.as_slice().split_first().ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat(format!(
+                    "empty key in the {} store",
+                    kv.storage_prefix
+                ))
+            })?;
+
+            match (kv.storage_prefix.as_str(), *prefix) {
+                (STAKING_STORE_KEY, VALIDATORS_KEY) => {
+                    let (val_addr, _) = decode_length_prefixed(rest)?;
+                    if kv.value.is_empty() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "validator is empty".into(),
+                        ));
+                    }
+                    validators.insert(val_addr.to_vec(), Validator::decode(kv.value.as_slice())?);
+                }
+                (STAKING_STORE_KEY, DELEGATION_KEY) => {
+                    let (del_addr, rest) = decode_length_prefixed(rest)?;
+                    let (val_addr, _) = decode_length_prefixed(rest)?;
+                    let delegation = if kv.value.is_empty() {
+                        None
+                    } else {
+                        Some(Delegation::decode(kv.value.as_slice())?)
+                    };
+                    delegations.insert((val_addr.to_vec(), del_addr.to_vec()), delegation);
+                }
+                (DISTRIBUTION_STORE_KEY, DELEGATOR_STARTING_INFO_PREFIX) => {
+                    let (val_addr, rest) = decode_length_prefixed(rest)?;
+                    let (del_addr, _) = decode_length_prefixed(rest)?;
+                    let starting_info = if kv.value.is_empty() {
+                        None
+                    } else {
+                        Some(DelegatorStartingInfo::decode(kv.value.as_slice())?)
+                    };
+                    starting_infos.push((val_addr.to_vec(), del_addr.to_vec(), starting_info));
+                }
+                (DISTRIBUTION_STORE_KEY, VALIDATOR_CURRENT_REWARDS_PREFIX) => {
+                    let (val_addr, _) = decode_length_prefixed(rest)?;
+                    if kv.value.is_empty() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "validator current rewards are empty".into(),
+                        ));
+                    }
+                    current_rewards.insert(
+                        val_addr.to_vec(),
+                        ValidatorCurrentRewards::decode(kv.value.as_slice())?,
+                    );
+                }
+                (DISTRIBUTION_STORE_KEY, VALIDATOR_HISTORICAL_REWARDS_PREFIX) => {
+                    let (val_addr, period) = decode_length_prefixed(rest)?;
+                    let period = u64::from_le_bytes(period.try_into().map_err(|_| {
+                        NeutronError::InvalidQueryResultFormat(
+                            "invalid historical rewards key".into(),
+                        )
+                    })?);
+                    historical_rewards.insert((val_addr.to_vec(), period), kv.value.clone());
+                }
+                (DISTRIBUTION_STORE_KEY, VALIDATOR_SLASH_EVENT_PREFIX) => {
+                    let (val_addr, rest) = decode_length_prefixed(rest)?;
+                    if rest.len() != 16 {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "invalid slash event key".into(),
+                        ));
+                    }
+                    // slash events are registered by their exact keys, so the event must exist
+                    if kv.value.is_empty() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "validator slash event is empty".into(),
+                        ));
+                    }
+                    let mut height = [0u8; 8];
+                    height.copy_from_slice(&rest[..8]);
+                    slash_events.entry(val_addr.to_vec()).or_default().push((
+                        u64::from_be_bytes(height),
+                        ValidatorSlashEvent::decode(kv.value.as_slice())?,
+                    ));
+                }
+                _ => {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "unexpected key {} in the {} store",
+                        kv.key, kv.storage_prefix
+                    )))
+                }
+            }
+        }
+
+        let mut rewards: Vec<DelegationRewards> = Vec::with_capacity(starting_infos.len());
+        let mut total: BTreeMap<String, Decimal> = BTreeMap::new();
+        let mut missing_keys: Vec<KVKey> = vec![];
+
+        for (val_addr, del_addr, starting_info) in starting_infos {
+            let delegation = delegations
+                .remove(&(val_addr.clone(), del_addr))
+                .ok_or_else(|| {
+                    NeutronError::InvalidQueryResultFormat("delegation is missing".into())
+                })?;
+            let (starting_info, delegation) = match (starting_info, delegation) {
+                (Some(starting_info), Some(delegation)) => (starting_info, delegation),
+                // the delegator has no delegation to the validator
+                (None, None) => continue,
+                _ => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "delegation doesn't match its starting info".into(),
+                    ))
+                }
+            };
+            let validator = validators.get(&val_addr).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat("validator is missing".into())
+            })?;
+            let current = current_rewards.get(&val_addr).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat(
+                    "validator current rewards are missing".into(),
+                )
+            })?;
+            let ending_period = current.period.checked_sub(1).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat("invalid validator current period".into())
+            })?;
+
+            // only slash events happened after the delegation started affect its rewards
+            let mut slashes: Vec<&ValidatorSlashEvent> = slash_events
+                .get(&val_addr)
+                .into_iter()
+                .flatten()
+                .filter(|(height, _)| *height >= starting_info.height)
+                .map(|(_, event)| event)
+                .collect();
+            slashes.sort_by_key(|event| event.validator_period);
+
+            // collect all the periods which historical rewards are required for the calculation
+            let mut periods: Vec<u64> = vec![starting_info.previous_period];
+            periods.extend(
+                slashes
+                    .iter()
+                    .map(|event| event.validator_period)
+                    .filter(|period| *period > starting_info.previous_period),
+            );
+            periods.push(ending_period);
+            periods.dedup();
+
+            let mut missing = false;
+            for period in &periods {
+                if !historical_rewards.contains_key(&(val_addr.clone(), *period)) {
+                    missing = true;
+                    missing_keys.push(KVKey {
+                        path: DISTRIBUTION_STORE_KEY.to_string(),
+                        key: Binary(create_validator_historical_rewards_key(&val_addr, *period)?),
+                    })
+                }
+            }
+            if missing {
+                continue;
+            }
+
+            let cumulative_ratio = |period: u64| -> NeutronResult<BTreeMap<String, Decimal>> {
+                let value = &historical_rewards[&(val_addr.clone(), period)];
+                if value.is_empty() {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "validator historical rewards for period {} are empty",
+                        period
+                    )));
+                }
+                parse_dec_coins(
+                    &ValidatorHistoricalRewards::decode(value.as_slice())?.cumulative_reward_ratio,
+                )
+            };
+
+            let mut delegation_rewards: BTreeMap<String, Decimal> = BTreeMap::new();
+            let mut starting_period = starting_info.previous_period;
+            let mut stake = parse_sdk_dec(&starting_info.stake)?;
+
+            // iterate through slashes and withdraw with calculated staking for distribution periods
+            for event in slashes {
+                if event.validator_period > starting_period {
+                    add_rewards_between(
+                        &mut delegation_rewards,
+                        &cumulative_ratio(starting_period)?,
+                        &cumulative_ratio(event.validator_period)?,
+                        stake,
+                    )?;
+
+                    let fraction = parse_sdk_dec(&event.fraction)?;
+                    if fraction > Decimal::one() {
+                        return Err(NeutronError::InvalidQueryResultFormat(
+                            "slash fraction is greater than one".into(),
+                        ));
+                    }
+                    stake = stake.checked_mul(Decimal::one() - fraction)?;
+                    starting_period = event.validator_period;
+                }
+            }
+
+            // the stake can't be greater than current delegation tokens,
+            // Cosmos-SDK allows it to be greater only by a rounding error of 3 * 10^-18.
+            // A greater difference means some slash events of the validator are not in the query,
+            // so the rewards can't be calculated
+            let validator_tokens = Uint128::from_str(&validator.tokens)?;
+            let delegator_shares = parse_sdk_dec(&validator.delegator_shares)?;
+            if delegator_shares.is_zero() {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "validator delegator shares are zero".into(),
+                ));
+            }
+            let current_stake = parse_sdk_dec(&delegation.shares)?
+                .checked_mul(Decimal::from_atomics(validator_tokens, 0)?)?
+                .div(delegator_shares);
+            if stake > current_stake {
+                let margin_of_error = Decimal::new(Uint128::new(3));
+                if stake > checked_add_dec(current_stake, margin_of_error)? {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "stake {} of the delegation to {} is greater than current stake {}, \
+                         slash events of the validator are missing",
+                        stake, validator.operator_address, current_stake
+                    )));
+                }
+                stake = current_stake
+            }
+
+            // the ending period is not finished yet, so we need to add current rewards to its cumulative ratio
+            // https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/distribution/keeper/validator.go
+            let mut ending_ratio = cumulative_ratio(ending_period)?;
+            if !validator_tokens.is_zero() {
+                for (denom, amount) in parse_dec_coins(&current.rewards)? {
+                    let ratio = ending_ratio.entry(denom).or_default();
+                    *ratio =
+                        checked_add_dec(*ratio, Decimal::new(amount.atomics() / validator_tokens))?;
+                }
+            }
+
+            add_rewards_between(
+                &mut delegation_rewards,
+                &cumulative_ratio(starting_period)?,
+                &ending_ratio,
+                stake,
+            )?;
+
+            for (denom, amount) in &delegation_rewards {
+                let sum = total.entry(denom.clone()).or_default();
+                *sum = checked_add_dec(*sum, *amount)?;
+            }
+
+            rewards.push(DelegationRewards {
+                validator_address: validator.operator_address.clone(),
+                rewards: truncate_dec_coins(&delegation_rewards),
+            })
+        }
+
+        // every delegation is registered along with its starting info
+        if !delegations.is_empty() {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "delegation starting info is missing".into(),
+            ));
+        }
+
+        Ok(PendingRewards {
+            rewards,
+            total: truncate_dec_coins(&total),
+            missing_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes signing info (liveness) of a validator on remote chain
+pub struct SigningInfo {
+    /// **address** is the consensus address of the validator, bech encoded
+    pub address: String,
+
+    /// **start_height** is the height at which the validator was first a candidate or was unjailed
+    pub start_height: i64,
+
+    /// **index_offset** is the index of the current block in the signed blocks window
+    pub index_offset: i64,
+
+    /// **jailed_until** is the time until which the validator is jailed
+    pub jailed_until: Option<Timestamp>,
+
+    /// **tombstoned** defines whether the validator has been tombstoned (permanently jailed) for double signing
+    pub tombstoned: bool,
+
+    /// **missed_blocks_counter** is the number of blocks the validator missed in the current signed blocks window
+    pub missed_blocks_counter: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Validator Signing Info Interchain Query**.
+/// Contains signing infos of validators,
+/// keys of validators without signing info on remote chain are listed in **absent_keys**.
+pub struct ValidatorSigningInfo {
+    pub signing_infos: Vec<SigningInfo>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl KVReconstruct for ValidatorSigningInfo {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<ValidatorSigningInfo> {
+        let mut signing_infos: Vec<SigningInfo> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut cons_addrs: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is VALIDATOR_SIGNING_INFO_KEY_PREFIX | len(consensus_address) | consensus_address
+            let rest = strip_key_prefix(
+                kv,
+                SLASHING_STORE_KEY,
+                VALIDATOR_SIGNING_INFO_KEY_PREFIX,
+                "signing info",
+            )?;
+            let (cons_addr, rest) = decode_length_prefixed(rest)?;
+            check_key_end(rest, "signing info")?;
+
+            if !cons_addrs.insert(cons_addr) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate signing info".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the validator doesn't have signing info on remote chain
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let info: CosmosValidatorSigningInfo =
+                CosmosValidatorSigningInfo::decode(kv.value.as_slice())?;
+            check_key_address(&info.address, cons_addr, "validator consensus address")?;
+
+            signing_infos.push(SigningInfo {
+                address: info.address,
+                start_height: info.start_height,
+                index_offset: info.index_offset,
+                jailed_until: info
+                    .jailed_until
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+                tombstoned: info.tombstoned,
+                missed_blocks_counter: info.missed_blocks_counter,
+            })
+        }
+
+        Ok(ValidatorSigningInfo {
+            signing_infos,
+            absent_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes a kind of vesting account
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/vesting/v1beta1/vesting.proto
+pub enum VestingKind {
+    Continuous,
+    Delayed,
+    Periodic,
+    PermanentLocked,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a period of a periodic vesting account
+pub struct VestingPeriod {
+    /// **length** is a duration of the period in seconds
+    pub length: i64,
+    pub amount: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes vesting schedule of a vesting account
+pub struct VestingSchedule {
+    pub kind: VestingKind,
+
+    /// **original_vesting** is the amount of coins which were vesting initially
+    pub original_vesting: Vec<Coin>,
+
+    /// **delegated_free** is the amount of vested coins which are delegated
+    pub delegated_free: Vec<Coin>,
+
+    /// **delegated_vesting** is the amount of vesting coins which are delegated
+    pub delegated_vesting: Vec<Coin>,
+
+    /// **start_time** is set for continuous and periodic vesting accounts only
+    pub start_time: Option<Timestamp>,
+
+    pub end_time: Timestamp,
+
+    /// **periods** is set for periodic vesting accounts only
+    pub periods: Vec<VestingPeriod>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes an account on remote chain
+pub struct Account {
+    pub address: String,
+
+    /// **account_type** is a type url of the account, e.g. **/cosmos.auth.v1beta1.BaseAccount**
+    pub account_type: String,
+
+    pub account_number: u64,
+    pub sequence: u64,
+
+    /// **pub_key** is not set until the account sends its first transaction
+    pub pub_key: Option<ProtobufAny>,
+
+    /// **module_name** is set for module accounts only
+    pub module_name: Option<String>,
+
+    /// **vesting** is set for vesting accounts only
+    pub vesting: Option<VestingSchedule>,
+}
+
+impl Account {
+    fn from_base_account(
+        account_type: String,
+        base_account: Option<BaseAccount>,
+    ) -> NeutronResult<Account> {
+        let base_account = base_account.ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("base account is empty".into())
+        })?;
+
+        Ok(Account {
+            address: base_account.address,
+            account_type,
+            account_number: base_account.account_number,
+            sequence: base_account.sequence,
+            pub_key: base_account
+                .pub_key
+                .map(|k| ProtobufAny::new(k.type_url, Binary::from(k.value))),
+            module_name: None,
+            vesting: None,
+        })
+    }
+
+    fn from_base_vesting_account(
+        account_type: String,
+        base_vesting_account: Option<BaseVestingAccount>,
+        kind: VestingKind,
+        start_time: Option<i64>,
+        periods: Vec<VestingPeriod>,
+    ) -> NeutronResult<Account> {
+        let base_vesting_account = base_vesting_account.ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("base vesting account is empty".into())
+        })?;
+
+        let mut account =
+            Account::from_base_account(account_type, base_vesting_account.base_account)?;
+        account.vesting = Some(VestingSchedule {
+            kind,
+            original_vesting: parse_coins(base_vesting_account.original_vesting)?,
+            delegated_free: parse_coins(base_vesting_account.delegated_free)?,
+            delegated_vesting: parse_coins(base_vesting_account.delegated_vesting)?,
+            start_time: start_time.map(|t| proto_timestamp(t, 0)).transpose()?,
+            end_time: proto_timestamp(base_vesting_account.end_time, 0)?,
+            periods,
+        });
+
+        Ok(account)
+    }
+
+    /// Decodes an account stored as **Any** in the **auth** module's storage
+    fn decode(value: &[u8]) -> NeutronResult<Account> {
+        let any = prost_types::Any::decode(value)?;
+        let data = any.value.as_slice();
+        let account_type = any.type_url.clone();
+
+        match any.type_url.as_str() {
+            "/cosmos.auth.v1beta1.BaseAccount" => {
+                Account::from_base_account(account_type, Some(BaseAccount::decode(data)?))
+            }
+            "/cosmos.auth.v1beta1.ModuleAccount" => {
+                let module_account = ModuleAccount::decode(data)?;
+                let mut account =
+                    Account::from_base_account(account_type, module_account.base_account)?;
+                account.module_name = Some(module_account.name);
+
+                Ok(account)
+            }
+            "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => {
+                let vesting_account = ContinuousVestingAccount::decode(data)?;
+                Account::from_base_vesting_account(
+                    account_type,
+                    vesting_account.base_vesting_account,
+                    VestingKind::Continuous,
+                    Some(vesting_account.start_time),
+                    vec![],
+                )
+            }
+            "/cosmos.vesting.v1beta1.DelayedVestingAccount" => Account::from_base_vesting_account(
+                account_type,
+                DelayedVestingAccount::decode(data)?.base_vesting_account,
+                VestingKind::Delayed,
+                None,
+                vec![],
+            ),
+            "/cosmos.vesting.v1beta1.PeriodicVestingAccount" => {
+                let vesting_account = PeriodicVestingAccount::decode(data)?;
+                let mut periods: Vec<VestingPeriod> =
+                    Vec::with_capacity(vesting_account.vesting_periods.len());
+                for period in vesting_account.vesting_periods {
+                    periods.push(VestingPeriod {
+                        length: period.length,
+                        amount: parse_coins(period.amount)?,
+                    })
+                }
+
+                Account::from_base_vesting_account(
+                    account_type,
+                    vesting_account.base_vesting_account,
+                    VestingKind::Periodic,
+                    Some(vesting_account.start_time),
+                    periods,
+                )
+            }
+            "/cosmos.vesting.v1beta1.PermanentLockedAccount" => Account::from_base_vesting_account(
+                account_type,
+                PermanentLockedAccount::decode(data)?.base_vesting_account,
+                VestingKind::PermanentLocked,
+                None,
+                vec![],
+            ),
+            _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+                "unsupported account type: {}",
+                account_type
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Account Interchain Query**.
+/// Contains info of an account on remote chain.
+pub struct AccountInfo {
+    /// **account** is not set if the account doesn't exist on remote chain
+    pub account: Option<Account>,
+}
+
+impl AccountInfo {
+    /// Returns **true** if the account exists on remote chain
+    pub fn exists(&self) -> bool {
+        self.account.is_some()
+    }
+}
+
+impl KVReconstruct for AccountInfo {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<AccountInfo> {
+        if storage_values.len() != 1 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected one storage value, got {}",
+                storage_values.len()
+            )));
+        }
+
+        let kv = &storage_values[0];
+
+        // the key is ACCOUNT_ADDRESS_PREFIX | address
+        let addr = strip_key_prefix(kv, ACCOUNT_STORE_KEY, ACCOUNT_ADDRESS_PREFIX, "account")?;
+        if addr.is_empty() {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "invalid account key".into(),
+            ));
+        }
+
+        if kv.value.is_empty() {
+            return Ok(AccountInfo { account: None });
+        }
+
+        let account = Account::decode(kv.value.as_slice())?;
+        check_key_address(&account.address, addr, "account")?;
+
+        Ok(AccountInfo {
+            account: Some(account),
+        })
+    }
+
+    fn kv_count() -> Option<usize> {
+        Some(1)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Wasm Contract State Interchain Query**.
+/// Contains JSON decoded value of a remote contract's **Item** or **Map** entry stored with cw-storage-plus.
+pub struct WasmContractState<T> {
+    /// **value** is not set if there is no value under the key in the remote contract's storage
+    pub value: Option<T>,
+}
+
+impl<T: DeserializeOwned> KVReconstruct for WasmContractState<T> {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<WasmContractState<T>> {
+        if storage_values.len() != 1 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected one storage value, got {}",
+                storage_values.len()
+            )));
+        }
+
+        let kv = &storage_values[0];
+
+        // the key is WASM_CONTRACT_STORE_PREFIX | contract_address | key,
+        // the contract address isn't length prefixed, so the key can't be split any further
+        let rest = strip_key_prefix(
+            kv,
+            WASM_STORE_KEY,
+            WASM_CONTRACT_STORE_PREFIX,
+            "wasm contract store",
+        )?;
+        if rest.is_empty() {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "invalid wasm contract store key".into(),
+            ));
+        }
+
+        if kv.value.is_empty() {
+            return Ok(WasmContractState { value: None });
+        }
+
+        Ok(WasmContractState {
+            value: Some(from_binary(&kv.value)?),
+        })
+    }
+
+    fn kv_count() -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Precision of the power approximation, the same as Osmosis uses (10^-8)
+const POW_PRECISION: u128 = 10_000_000_000;
+
+/// Maximum number of iterations of the power approximation, the same as Osmosis uses
+const POW_ITERATION_LIMIT: u128 = 150_000;
+
+/// Multiplier applied to stableswap pool reserves when they are scaled with scaling factors
+/// to keep the precision of integer calculations
+const STABLESWAP_SCALE_PRECISION: u128 = 1_000_000_000_000;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Type of Osmosis gamm pool
+pub enum GammPoolType {
+    Balancer,
+    Stableswap,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Asset of Osmosis gamm pool
+pub struct GammPoolAsset {
+    /// **token** is a denom and reserve amount of the asset in the pool
+    pub token: Coin,
+
+    /// **weight** is a weight of the asset in a balancer pool, it's not set for stableswap pools
+    pub weight: Option<Uint128>,
+
+    /// **scaling_factor** is a scaling factor of the asset in a stableswap pool, it's not set for balancer pools
+    pub scaling_factor: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Gamm Pool Interchain Query**.
+/// Contains state of Osmosis gamm pool and allows to estimate swaps against it.
+pub struct GammPool {
+    pub id: u64,
+
+    /// **address** is an address of the pool account
+    pub address: String,
+
+    pub pool_type: GammPoolType,
+
+    pub assets: Vec<GammPoolAsset>,
+
+    pub swap_fee: Decimal,
+
+    pub exit_fee: Decimal,
+
+    /// **total_shares** is a total amount of the pool LP tokens
+    pub total_shares: Coin,
+}
+
+impl GammPool {
+    /// Returns the pool asset with **denom**
+    pub fn asset(&self, denom: &str) -> NeutronResult<&GammPoolAsset> {
+        self.assets
+            .iter()
+            .find(|asset| asset.token.denom == denom)
+            .ok_or_else(|| {
+                NeutronError::Std(StdError::generic_err(format!(
+                    "denom {} is not found in the pool {}",
+                    denom, self.id
+                )))
+            })
+    }
+
+    /// Returns spot price of **base_denom** in **quote_denom**, i.e. amount of the quote asset
+    /// paid for one unit of the base asset. The swap fee isn't included into the spot price.
+    pub fn spot_price(&self, base_denom: &str, quote_denom: &str) -> NeutronResult<Decimal> {
+        let (base, quote) = self.swap_assets(base_denom, quote_denom)?;
+
+        match self.pool_type {
+            // (quote_balance / quote_weight) / (base_balance / base_weight)
+            GammPoolType::Balancer => decimal_from_ratio(
+                Uint512::from(quote.token.amount)
+                    .checked_mul(Uint512::from(base.weight.unwrap_or_default()))?,
+                Uint512::from(base.token.amount)
+                    .checked_mul(Uint512::from(quote.weight.unwrap_or_default()))?,
+            ),
+            // the spot price is a ratio of partial derivatives of the CFMM function xy(x^2 + y^2 + w),
+            // it's converted from the scaled reserves units to the assets units with the scaling factors
+            GammPoolType::Stableswap => {
+                let (x, y, w) = self.stableswap_scaled_reserves(base, quote)?;
+                let three = Uint512::from(3u8);
+
+                let x_derivative = y.checked_mul(
+                    three
+                        .checked_mul(x.checked_mul(x)?)?
+                        .checked_add(y.checked_mul(y)?)?
+                        .checked_add(w)?,
+                )?;
+                let y_derivative = x.checked_mul(
+                    x.checked_mul(x)?
+                        .checked_add(three.checked_mul(y.checked_mul(y)?)?)?
+                        .checked_add(w)?,
+                )?;
+
+                decimal_from_ratio(
+                    x_derivative.checked_mul(Uint512::from(quote.scaling_factor.unwrap_or(1)))?,
+                    y_derivative.checked_mul(Uint512::from(base.scaling_factor.unwrap_or(1)))?,
+                )
+            }
+        }
+    }
+
+    /// Returns amount of **token_out_denom** expected to be received by swapping **token_in** against the pool.
+    /// The swap fee is deducted from **token_in** and the result is rounded down
+    /// in the same way as Osmosis calculates **MsgSwapExactAmountIn** output.
+    pub fn expected_out(&self, token_in: &Coin, token_out_denom: &str) -> NeutronResult<Uint128> {
+        let (asset_in, asset_out) = self.swap_assets(&token_in.denom, token_out_denom)?;
+
+        if self.swap_fee > Decimal::one() {
+            return Err(NeutronError::Std(StdError::generic_err(format!(
+                "swap fee {} of the pool {} is greater than 1",
+                self.swap_fee, self.id
+            ))));
+        }
+
+        let amount_in = token_in.amount * (Decimal::one() - self.swap_fee);
+        if amount_in.is_zero() {
+            return Ok(Uint128::zero());
+        }
+
+        match self.pool_type {
+            // balance_out * (1 - (balance_in / (balance_in + amount_in)) ^ (weight_in / weight_out))
+            GammPoolType::Balancer => {
+                let balance_ratio = decimal_from_ratio(
+                    Uint512::from(asset_in.token.amount),
+                    Uint512::from(asset_in.token.amount.checked_add(amount_in)?),
+                )?;
+                let weight_ratio = decimal_from_ratio(
+                    Uint512::from(asset_in.weight.unwrap_or_default()),
+                    Uint512::from(asset_out.weight.unwrap_or_default()),
+                )?;
+
+                let balance_ratio_pow = pow_dec(balance_ratio, weight_ratio)?;
+
+                // the approximated power of a ratio below 1 might slightly exceed 1
+                Ok(asset_out.token.amount
+                    * (Decimal::one() - balance_ratio_pow.min(Decimal::one())))
+            }
+            // the out amount is the difference between the out asset reserve before the swap and
+            // the minimal reserve which keeps the CFMM value after the in asset reserve is increased
+            GammPoolType::Stableswap => {
+                let (x, y, w) = self.stableswap_scaled_reserves(asset_in, asset_out)?;
+                let cfmm = stableswap_cfmm(x, y, w)?;
+                let x_after = x.checked_add(stableswap_scale(amount_in, asset_in)?)?;
+
+                let two = Uint512::from(2u8);
+                let (mut low, mut high) = (Uint512::zero(), y);
+                while low < high {
+                    let mid = low + (high - low) / two;
+                    if stableswap_cfmm(x_after, mid, w)? >= cfmm {
+                        high = mid;
+                    } else {
+                        low = mid + Uint512::from(1u8);
+                    }
+                }
+
+                stableswap_unscale(y - low, asset_out)
+            }
+        }
+    }
+
+    /// Returns minimal amount of **token_out_denom** to receive by swapping **token_in** against the pool
+    /// if the actual amount is allowed to be less than the expected one by **max_slippage** (e.g. 0.01 for 1%).
+    /// The result can be used as **token_out_min_amount** of **MsgSwapExactAmountIn**
+    pub fn min_out_amount(
+        &self,
+        token_in: &Coin,
+        token_out_denom: &str,
+        max_slippage: Decimal,
+    ) -> NeutronResult<Uint128> {
+        if max_slippage > Decimal::one() {
+            return Err(NeutronError::Std(StdError::generic_err(format!(
+                "max slippage {} is greater than 1",
+                max_slippage
+            ))));
+        }
+
+        Ok(self.expected_out(token_in, token_out_denom)? * (Decimal::one() - max_slippage))
+    }
+
+    /// Returns a pair of different pool assets with non-zero reserves
+    fn swap_assets(
+        &self,
+        denom_in: &str,
+        denom_out: &str,
+    ) -> NeutronResult<(&GammPoolAsset, &GammPoolAsset)> {
+        if denom_in == denom_out {
+            return Err(NeutronError::Std(StdError::generic_err(format!(
+                "can't swap denom {} to itself",
+                denom_in
+            ))));
+        }
+
+        let (asset_in, asset_out) = (self.asset(denom_in)?, self.asset(denom_out)?);
+        if asset_in.token.amount.is_zero() || asset_out.token.amount.is_zero() {
+            return Err(NeutronError::Std(StdError::generic_err(format!(
+                "pool {} has no liquidity",
+                self.id
+            ))));
+        }
+
+        Ok((asset_in, asset_out))
+    }
+
+    /// Returns scaled reserves of **x** and **y** assets and the sum of squares
+    /// of the other scaled reserves of a stableswap pool
+    fn stableswap_scaled_reserves(
+        &self,
+        x: &GammPoolAsset,
+        y: &GammPoolAsset,
+    ) -> NeutronResult<(Uint512, Uint512, Uint512)> {
+        let mut w = Uint512::zero();
+        for asset in &self.assets {
+            if asset.token.denom == x.token.denom || asset.token.denom == y.token.denom {
+                continue;
+            }
+
+            let reserve = stableswap_scale(asset.token.amount, asset)?;
+            w = w.checked_add(reserve.checked_mul(reserve)?)?;
+        }
+
+        Ok((
+            stableswap_scale(x.token.amount, x)?,
+            stableswap_scale(y.token.amount, y)?,
+            w,
+        ))
+    }
+
+    /// Decodes a pool stored as **Any** in the **gamm** module's storage
+    fn decode(value: &[u8]) -> NeutronResult<GammPool> {
+        let any = prost_types::Any::decode(value)?;
+        let data = any.value.as_slice();
+
+        match any.type_url.as_str() {
+            GAMM_BALANCER_POOL_TYPE_URL => {
+                let pool = OsmosisBalancerPool::decode(data)?;
+
+                let mut assets: Vec<GammPoolAsset> = Vec::with_capacity(pool.pool_assets.len());
+                for asset in pool.pool_assets {
+                    let token = asset.token.ok_or_else(|| {
+                        NeutronError::InvalidQueryResultFormat("pool asset token is empty".into())
+                    })?;
+
+                    assets.push(GammPoolAsset {
+                        token: parse_osmosis_coin(token)?,
+                        weight: Some(Uint128::from_str(&asset.weight)?),
+                        scaling_factor: None,
+                    })
+                }
+
+                GammPool::new(
+                    pool.id,
+                    pool.address,
+                    GammPoolType::Balancer,
+                    assets,
+                    pool.pool_params.map(|p| (p.swap_fee, p.exit_fee)),
+                    pool.total_shares,
+                )
+            }
+            GAMM_STABLESWAP_POOL_TYPE_URL => {
+                let pool = OsmosisStableswapPool::decode(data)?;
+
+                // scaling factors might be not set for the pools created before they were introduced
+                if !pool.scaling_factor.is_empty()
+                    && pool.scaling_factor.len() != pool.pool_liquidity.len()
+                {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "pool has {} assets and {} scaling factors",
+                        pool.pool_liquidity.len(),
+                        pool.scaling_factor.len()
+                    )));
+                }
+
+                let mut assets: Vec<GammPoolAsset> = Vec::with_capacity(pool.pool_liquidity.len());
+                for (i, token) in pool.pool_liquidity.into_iter().enumerate() {
+                    assets.push(GammPoolAsset {
+                        token: parse_osmosis_coin(token)?,
+                        weight: None,
+                        scaling_factor: Some(pool.scaling_factor.get(i).copied().unwrap_or(1)),
+                    })
+                }
+
+                GammPool::new(
+                    pool.id,
+                    pool.address,
+                    GammPoolType::Stableswap,
+                    assets,
+                    pool.pool_params.map(|p| (p.swap_fee, p.exit_fee)),
+                    pool.total_shares,
+                )
+            }
+            _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+                "unsupported gamm pool type: {}",
+                any.type_url
+            ))),
+        }
+    }
+
+    fn new(
+        id: u64,
+        address: String,
+        pool_type: GammPoolType,
+        assets: Vec<GammPoolAsset>,
+        pool_params: Option<(String, String)>,
+        total_shares: Option<OsmosisCoin>,
+    ) -> NeutronResult<GammPool> {
+        // balancer and stableswap pools have different params types, only the fees are taken from them
+        let (swap_fee, exit_fee) = pool_params.ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("pool params are empty".into())
+        })?;
+        let total_shares = total_shares.ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("pool total shares are empty".into())
+        })?;
+
+        Ok(GammPool {
+            id,
+            address,
+            pool_type,
+            assets,
+            swap_fee: parse_sdk_dec(&swap_fee)?,
+            exit_fee: parse_sdk_dec(&exit_fee)?,
+            total_shares: parse_osmosis_coin(total_shares)?,
+        })
+    }
+}
+
+impl KVReconstruct for GammPool {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GammPool> {
+        if storage_values.len() != 1 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected one storage value, got {}",
+                storage_values.len()
+            )));
+        }
+
+        let kv = &storage_values[0];
+        let rest = strip_key_prefix(kv, GAMM_STORE_KEY, GAMM_POOLS_KEY_PREFIX, "pool")?;
+        let (pool_id, rest) = split_key_id(rest, "pool")?;
+        check_key_end(rest, "pool")?;
+
+        if kv.value.is_empty() {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "pool {} doesn't exist on remote chain",
+                pool_id
+            )));
+        }
+
+        let pool = GammPool::decode(kv.value.as_slice())?;
+        if pool.id != pool_id {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "pool {} is stored under the key of pool {}",
+                pool.id, pool_id
+            )));
+        }
+
+        Ok(pool)
+    }
+
+    fn kv_count() -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Calculates ratio of big integers as **Decimal**
+fn decimal_from_ratio(numerator: Uint512, denominator: Uint512) -> NeutronResult<Decimal> {
+    let atomics = numerator
+        .checked_mul(Uint512::from(DECIMAL_FRACTIONAL))?
+        .checked_div(denominator)
+        .map_err(StdError::from)?;
+
+    Ok(Decimal::new(
+        Uint128::try_from(atomics).map_err(|_| DecimalRangeExceeded)?,
+    ))
+}
+
+/// Calculates **base** to the power of **exp** for **base** in (0, 1] like Osmosis **osmomath.Pow** does:
+/// the integer part of the exponent is applied exactly, the fractional part is approximated
+fn pow_dec(base: Decimal, exp: Decimal) -> NeutronResult<Decimal> {
+    let exp_int = exp.atomics().u128() / DECIMAL_FRACTIONAL;
+    let exp_frac = Decimal::new(Uint128::new(exp.atomics().u128() % DECIMAL_FRACTIONAL));
+
+    // base doesn't exceed 1, so its powers can't overflow and the huge exponents just give zero
+    let int_pow = base.checked_pow(u32::try_from(exp_int).unwrap_or(u32::MAX))?;
+    if exp_frac.is_zero() {
+        return Ok(int_pow);
+    }
+
+    Ok(int_pow.checked_mul(pow_approx(base, exp_frac)?)?)
+}
+
+/// Approximates **base** to the power of **exp** for **base** in (0, 1] and **exp** in (0, 1)
+/// with the binomial series (1 - x)^exp = 1 + sum(C(exp, k) * (-x)^k) like Osmosis **osmomath.PowApprox** does
+fn pow_approx(base: Decimal, exp: Decimal) -> NeutronResult<Decimal> {
+    let x = Decimal::new(Decimal::one().atomics().checked_sub(base.atomics())?);
+
+    // the sum is kept as a pair of positive and negative parts since Decimal is unsigned
+    let mut positive_sum = Decimal::one();
+    let mut negative_sum = Decimal::zero();
+    let mut term = Decimal::one();
+    let mut negative = false;
+
+    let mut k: u128 = 1;
+    while term.atomics().u128() >= POW_PRECISION {
+        if k > POW_ITERATION_LIMIT {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "failed to approximate power: iteration limit exceeded",
+            )));
+        }
+
+        // term_k = term_(k-1) * (exp - (k - 1)) * (-x) / k
+        let prev_k = Uint128::new((k - 1) * DECIMAL_FRACTIONAL);
+        let (c, c_negative) = if exp.atomics() >= prev_k {
+            (Decimal::new(exp.atomics() - prev_k), false)
+        } else {
+            (Decimal::new(prev_k - exp.atomics()), true)
+        };
+
+        term = Decimal::new(term.checked_mul(c)?.checked_mul(x)?.atomics() / Uint128::new(k));
+        if term.is_zero() {
+            break;
+        }
+
+        // x is always taken with the negative sign
+        negative = !negative;
+        if c_negative {
+            negative = !negative;
+        }
+
+        if negative {
+            negative_sum = checked_add_dec(negative_sum, term)?;
+        } else {
+            positive_sum = checked_add_dec(positive_sum, term)?;
+        }
+
+        k += 1;
+    }
+
+    Ok(Decimal::new(
+        positive_sum
+            .atomics()
+            .saturating_sub(negative_sum.atomics()),
+    ))
+}
+
+/// Scales **amount** of stableswap pool **asset** with its scaling factor
+fn stableswap_scale(amount: Uint128, asset: &GammPoolAsset) -> NeutronResult<Uint512> {
+    Ok(Uint512::from(amount)
+        .checked_mul(Uint512::from(STABLESWAP_SCALE_PRECISION))?
+        .checked_div(Uint512::from(asset.scaling_factor.unwrap_or(1)))
+        .map_err(StdError::from)?)
+}
+
+/// Converts scaled **amount** of stableswap pool **asset** back to the asset units rounding it down
+fn stableswap_unscale(amount: Uint512, asset: &GammPoolAsset) -> NeutronResult<Uint128> {
+    let unscaled = amount
+        .checked_mul(Uint512::from(asset.scaling_factor.unwrap_or(1)))?
+        .checked_div(Uint512::from(STABLESWAP_SCALE_PRECISION))
+        .map_err(StdError::from)?;
+
+    Uint128::try_from(unscaled).map_err(|e| NeutronError::Std(StdError::generic_err(e.to_string())))
+}
+
+/// Calculates the Osmosis stableswap CFMM function xy(x^2 + y^2 + w)
+fn stableswap_cfmm(x: Uint512, y: Uint512, w: Uint512) -> NeutronResult<Uint512> {
+    let squares_sum = x
+        .checked_mul(x)?
+        .checked_add(y.checked_mul(y)?)?
+        .checked_add(w)?;
+
+    Ok(x.checked_mul(y)?.checked_mul(squares_sum)?)
+}
+
+/// Denom trace of ibc-go **transfer** module as it's stored on chain
+/// https://github.com/cosmos/ibc-go/blob/v3.0.0/proto/ibc/applications/transfer/v1/transfer.proto
+#[derive(Clone, PartialEq, prost::Message)]
+struct IbcDenomTrace {
+    #[prost(string, tag = "1")]
+    path: String,
+    #[prost(string, tag = "2")]
+    base_denom: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Trace of a token transferred over IBC
+pub struct DenomTrace {
+    /// **path** is a chain of port/channel identifiers the token was transferred through,
+    /// e.g. **transfer/channel-0**
+    pub path: String,
+
+    /// **base_denom** is a denom of the token on its native chain
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Returns IBC denom (**ibc/{hash}**) of the token
+    pub fn ibc_denom(&self) -> String {
+        get_ibc_denom(&self.path, &self.base_denom)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Denom Traces Interchain Query**.
+/// Contains traces of IBC denoms on remote chain,
+/// keys of denoms which don't have a trace on remote chain are listed in **absent_keys**.
+pub struct DenomTraces {
+    pub traces: Vec<DenomTrace>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl DenomTraces {
+    /// Returns trace of IBC denom (**ibc/{hash}**)
+    pub fn trace(&self, ibc_denom: &str) -> Option<&DenomTrace> {
+        self.traces
+            .iter()
+            .find(|trace| trace.ibc_denom() == ibc_denom)
+    }
+}
+
+impl KVReconstruct for DenomTraces {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<DenomTraces> {
+        let mut traces: Vec<DenomTrace> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut hashes: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is DENOM_TRACE_KEY_PREFIX | hash
+            let hash = strip_key_prefix(
+                kv,
+                TRANSFER_STORE_KEY,
+                DENOM_TRACE_KEY_PREFIX,
+                "denom trace",
+            )?;
+            if hash.len() != IBC_DENOM_HASH_LEN {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "invalid denom trace key".into(),
+                ));
+            }
+
+            if !hashes.insert(hash) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate denom trace".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the denom trace doesn't exist on remote chain
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let trace = IbcDenomTrace::decode(kv.value.as_slice())?;
+            let trace = DenomTrace {
+                path: trace.path,
+                base_denom: trace.base_denom,
+            };
+            // a trace without path is a native denom which doesn't have a hash
+            if trace.path.is_empty() || decode_ibc_denom_hash(&trace.ibc_denom())? != hash {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "denom trace {}/{} is stored under the key of another hash",
+                    trace.path, trace.base_denom
+                )));
+            }
+
+            traces.push(trace)
+        }
+
+        Ok(DenomTraces {
+            traces,
+            absent_keys,
+        })
+    }
+}
+
+/// Grant of Cosmos-SDK **authz** module as it's stored on chain.
+/// **expiration** is optional since Cosmos SDK v0.46
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/authz/v1beta1/authz.proto
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosAuthzGrant {
+    #[prost(message, optional, tag = "1")]
+    authorization: Option<prost_types::Any>,
+    #[prost(message, optional, tag = "2")]
+    expiration: Option<prost_types::Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosGenericAuthorization {
+    #[prost(string, tag = "1")]
+    msg: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosSendAuthorization {
+    #[prost(message, repeated, tag = "1")]
+    spend_limit: Vec<CosmosCoin>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosStakeAuthorizationValidators {
+    #[prost(string, repeated, tag = "1")]
+    address: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosStakeAuthorization {
+    #[prost(message, optional, tag = "1")]
+    max_tokens: Option<CosmosCoin>,
+    #[prost(message, optional, tag = "2")]
+    allow_list: Option<CosmosStakeAuthorizationValidators>,
+    #[prost(message, optional, tag = "3")]
+    deny_list: Option<CosmosStakeAuthorizationValidators>,
+    #[prost(int32, tag = "4")]
+    authorization_type: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Type of staking messages a stake authorization is given for
+pub enum StakeAuthorizationType {
+    Unspecified,
+    Delegate,
+    Undelegate,
+    Redelegate,
+}
+
+impl StakeAuthorizationType {
+    fn from_i32(value: i32) -> NeutronResult<StakeAuthorizationType> {
+        match value {
+            0 => Ok(StakeAuthorizationType::Unspecified),
+            1 => Ok(StakeAuthorizationType::Delegate),
+            2 => Ok(StakeAuthorizationType::Undelegate),
+            3 => Ok(StakeAuthorizationType::Redelegate),
+            _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+                "unknown stake authorization type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Authorization given to a grantee by **authz** grant
+pub enum Authorization {
+    /// Allows the grantee to execute any message of the **msg** type
+    Generic { msg: String },
+    /// Allows the grantee to send tokens up to **spend_limit** on behalf of the granter
+    Send { spend_limit: Vec<Coin> },
+    /// Allows the grantee to execute staking messages of **authorization_type** on behalf of the granter.
+    /// **max_tokens** is not set if the amount is unlimited,
+    /// only one of **allow_list** and **deny_list** of validators is non-empty
+    Stake {
+        max_tokens: Option<Coin>,
+        authorization_type: StakeAuthorizationType,
+        allow_list: Vec<String>,
+        deny_list: Vec<String>,
+    },
+}
+
+impl Authorization {
+    /// Decodes an authorization stored as **Any** in the **authz** grant
+    fn decode(any: prost_types::Any) -> NeutronResult<Authorization> {
+        let data = any.value.as_slice();
+
+        match any.type_url.as_str() {
+            "/cosmos.authz.v1beta1.GenericAuthorization" => Ok(Authorization::Generic {
+                msg: CosmosGenericAuthorization::decode(data)?.msg,
+            }),
+            "/cosmos.bank.v1beta1.SendAuthorization" => Ok(Authorization::Send {
+                spend_limit: parse_coins(CosmosSendAuthorization::decode(data)?.spend_limit)?,
+            }),
+            "/cosmos.staking.v1beta1.StakeAuthorization" => {
+                let auth = CosmosStakeAuthorization::decode(data)?;
+                Ok(Authorization::Stake {
+                    max_tokens: auth.max_tokens.map(parse_coin).transpose()?,
+                    authorization_type: StakeAuthorizationType::from_i32(auth.authorization_type)?,
+                    allow_list: auth.allow_list.map(|v| v.address).unwrap_or_default(),
+                    deny_list: auth.deny_list.map(|v| v.address).unwrap_or_default(),
+                })
+            }
+            _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+                "unsupported authorization type: {}",
+                any.type_url
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Grant of **authz** module given by a granter to a grantee for a particular message type
+pub struct AuthzGrant {
+    /// **msg_type_url** is a type url of the message the grant is given for
+    pub msg_type_url: String,
+
+    pub authorization: Authorization,
+
+    /// **expiration** is not set if the grant never expires
+    pub expiration: Option<Timestamp>,
+}
+
+impl AuthzGrant {
+    /// Returns **true** if the grant is expired at **time**
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        self.expiration
+            .map(|expiration| expiration <= time)
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Authz Grants Interchain Query**.
+/// Contains grants given by a granter to a grantee on remote chain,
+/// keys of message types without a grant on remote chain are listed in **absent_keys**.
+pub struct AuthzGrants {
+    pub grants: Vec<AuthzGrant>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl AuthzGrants {
+    /// Returns the grant for **msg_type_url**
+    pub fn grant(&self, msg_type_url: &str) -> Option<&AuthzGrant> {
+        self.grants
+            .iter()
+            .find(|grant| grant.msg_type_url == msg_type_url)
+    }
+
+    /// Returns **true** if there is a grant for **msg_type_url** which isn't expired at **time**
+    pub fn is_granted(&self, msg_type_url: &str, time: Timestamp) -> bool {
+        self.grant(msg_type_url)
+            .map(|grant| !grant.is_expired(time))
+            .unwrap_or(false)
+    }
+}
+
+impl KVReconstruct for AuthzGrants {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<AuthzGrants> {
+        let mut grants: Vec<AuthzGrant> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut granter_grantee: Option<(&[u8], &[u8])> = None;
+        let mut msg_type_urls: BTreeSet<String> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is AUTHZ_GRANT_KEY_PREFIX | len(granter) | granter | len(grantee) | grantee | msg_type_url
+            let rest =
+                strip_key_prefix(kv, AUTHZ_STORE_KEY, AUTHZ_GRANT_KEY_PREFIX, "authz grant")?;
+            let (granter_addr, rest) = decode_length_prefixed(rest)?;
+            let (grantee_addr, msg_type_url) = decode_length_prefixed(rest)?;
+            let msg_type_url = String::from_utf8(msg_type_url.to_vec()).map_err(StdError::from)?;
+            if msg_type_url.is_empty() {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "invalid authz grant key".into(),
+                ));
+            }
+
+            match granter_grantee {
+                None => granter_grantee = Some((granter_addr, grantee_addr)),
+                Some(addrs) if addrs != (granter_addr, grantee_addr) => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "authz grants of different granters or grantees".into(),
+                    ))
+                }
+                _ => {}
+            }
+
+            if !msg_type_urls.insert(msg_type_url.clone()) {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "duplicate authz grant for {}",
+                    msg_type_url
+                )));
+            }
+
+            if kv.value.is_empty() {
+                // the grant for the message type doesn't exist
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let grant = CosmosAuthzGrant::decode(kv.value.as_slice())?;
+            let authorization = grant.authorization.ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat("authz grant authorization is empty".into())
+            })?;
+
+            grants.push(AuthzGrant {
+                msg_type_url,
+                authorization: Authorization::decode(authorization)?,
+                expiration: grant
+                    .expiration
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+            })
+        }
+
+        Ok(AuthzGrants {
+            grants,
+            absent_keys,
+        })
+    }
+}
+
+/// Grant of Cosmos-SDK **feegrant** module as it's stored on chain
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/feegrant/v1beta1/feegrant.proto
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosFeeGrant {
+    #[prost(string, tag = "1")]
+    granter: String,
+    #[prost(string, tag = "2")]
+    grantee: String,
+    #[prost(message, optional, tag = "3")]
+    allowance: Option<prost_types::Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosBasicAllowance {
+    #[prost(message, repeated, tag = "1")]
+    spend_limit: Vec<CosmosCoin>,
+    #[prost(message, optional, tag = "2")]
+    expiration: Option<prost_types::Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosPeriodicAllowance {
+    #[prost(message, optional, tag = "1")]
+    basic: Option<CosmosBasicAllowance>,
+    #[prost(message, optional, tag = "2")]
+    period: Option<prost_types::Duration>,
+    #[prost(message, repeated, tag = "3")]
+    period_spend_limit: Vec<CosmosCoin>,
+    #[prost(message, repeated, tag = "4")]
+    period_can_spend: Vec<CosmosCoin>,
+    #[prost(message, optional, tag = "5")]
+    period_reset: Option<prost_types::Timestamp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CosmosAllowedMsgAllowance {
+    #[prost(message, optional, tag = "1")]
+    allowance: Option<prost_types::Any>,
+    #[prost(string, repeated, tag = "2")]
+    allowed_messages: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Periodic limit of a fee allowance
+pub struct PeriodicFeeAllowance {
+    /// **period_seconds** is a duration of the period in seconds
+    pub period_seconds: i64,
+
+    /// **period_spend_limit** is the maximum amount of fees which can be spent per period
+    pub period_spend_limit: Vec<Coin>,
+
+    /// **period_can_spend** is the amount of fees which is left to spend in the current period
+    pub period_can_spend: Vec<Coin>,
+
+    /// **period_reset** is the time when the current period ends
+    pub period_reset: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Fee allowance given by a granter to a grantee
+pub struct FeeAllowance {
+    pub granter: String,
+
+    pub grantee: String,
+
+    /// **spend_limit** is the maximum amount of fees which can be spent, empty if the amount is unlimited
+    pub spend_limit: Vec<Coin>,
+
+    /// **expiration** is not set if the allowance never expires
+    pub expiration: Option<Timestamp>,
+
+    /// **periodic** is set if the allowance is limited per period
+    pub periodic: Option<PeriodicFeeAllowance>,
+
+    /// **allowed_messages** is set if the allowance is limited to particular message types
+    pub allowed_messages: Option<Vec<String>>,
+}
+
+impl FeeAllowance {
+    /// Returns **true** if the allowance is expired at **time**
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        self.expiration
+            .map(|expiration| expiration <= time)
+            .unwrap_or(false)
+    }
+
+    /// Fills the allowance limits from an allowance stored as **Any**
+    fn decode_allowance(&mut self, any: prost_types::Any) -> NeutronResult<()> {
+        let data = any.value.as_slice();
+
+        match any.type_url.as_str() {
+            "/cosmos.feegrant.v1beta1.BasicAllowance" => {
+                self.set_basic(Some(CosmosBasicAllowance::decode(data)?))
+            }
+            "/cosmos.feegrant.v1beta1.PeriodicAllowance" => {
+                let allowance = CosmosPeriodicAllowance::decode(data)?;
+                self.set_basic(allowance.basic)?;
+                self.periodic = Some(PeriodicFeeAllowance {
+                    period_seconds: allowance.period.map(|p| p.seconds).unwrap_or_default(),
+                    period_spend_limit: parse_coins(allowance.period_spend_limit)?,
+                    period_can_spend: parse_coins(allowance.period_can_spend)?,
+                    period_reset: allowance
+                        .period_reset
+                        .map(|t| proto_timestamp(t.seconds, t.nanos))
+                        .transpose()?,
+                });
+
+                Ok(())
+            }
+            "/cosmos.feegrant.v1beta1.AllowedMsgAllowance" => {
+                let allowance = CosmosAllowedMsgAllowance::decode(data)?;
+                self.allowed_messages = Some(allowance.allowed_messages);
+                self.decode_allowance(allowance.allowance.ok_or_else(|| {
+                    NeutronError::InvalidQueryResultFormat("fee allowance is empty".into())
+                })?)
+            }
+            _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+                "unsupported fee allowance type: {}",
+                any.type_url
+            ))),
+        }
+    }
+
+    fn set_basic(&mut self, basic: Option<CosmosBasicAllowance>) -> NeutronResult<()> {
+        if let Some(basic) = basic {
+            self.spend_limit = parse_coins(basic.spend_limit)?;
+            self.expiration = basic
+                .expiration
+                .map(|t| proto_timestamp(t.seconds, t.nanos))
+                .transpose()?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Fee Allowance Interchain Query**.
+/// Contains fee allowance given by a granter to a grantee on remote chain.
+pub struct FeeGrantAllowance {
+    /// **allowance** is not set if the allowance doesn't exist on remote chain
+    pub allowance: Option<FeeAllowance>,
+}
+
+impl FeeGrantAllowance {
+    /// Returns **true** if the allowance exists and isn't expired at **time**
+    pub fn is_valid(&self, time: Timestamp) -> bool {
+        self.allowance
+            .as_ref()
+            .map(|allowance| !allowance.is_expired(time))
+            .unwrap_or(false)
+    }
+}
+
+impl KVReconstruct for FeeGrantAllowance {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<FeeGrantAllowance> {
+        if storage_values.len() != 1 {
+            return Err(NeutronError::InvalidQueryResultFormat(format!(
+                "expected one storage value, got {}",
+                storage_values.len()
+            )));
+        }
+
+        let kv = &storage_values[0];
+
+        // the key is FEE_ALLOWANCE_KEY_PREFIX | len(grantee) | grantee | len(granter) | granter
+        let rest = strip_key_prefix(
+            kv,
+            FEEGRANT_STORE_KEY,
+            FEE_ALLOWANCE_KEY_PREFIX,
+            "fee allowance",
+        )?;
+        let (grantee_addr, rest) = decode_length_prefixed(rest)?;
+        let (granter_addr, rest) = decode_length_prefixed(rest)?;
+        check_key_end(rest, "fee allowance")?;
+
+        if kv.value.is_empty() {
+            return Ok(FeeGrantAllowance { allowance: None });
+        }
+
+        let grant = CosmosFeeGrant::decode(kv.value.as_slice())?;
+        check_key_address(&grant.granter, granter_addr, "granter")?;
+        check_key_address(&grant.grantee, grantee_addr, "grantee")?;
+        let mut allowance = FeeAllowance {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            spend_limit: vec![],
+            expiration: None,
+            periodic: None,
+            allowed_messages: None,
+        };
+        allowance.decode_allowance(grant.allowance.ok_or_else(|| {
+            NeutronError::InvalidQueryResultFormat("fee allowance is empty".into())
+        })?)?;
+
+        Ok(FeeGrantAllowance {
+            allowance: Some(allowance),
+        })
+    }
+
+    fn kv_count() -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Parses protobuf representation of Cosmos-SDK **sdk.Coins**
+fn parse_coins(coins: Vec<CosmosCoin>) -> NeutronResult<Vec<Coin>> {
+    coins.into_iter().map(parse_coin).collect()
+}
+
+/// Parses protobuf representation of Cosmos-SDK **sdk.Coin**
+fn parse_coin(coin: CosmosCoin) -> NeutronResult<Coin> {
+    Ok(Coin::new(
+        Uint128::from_str(&coin.amount)?.u128(),
+        coin.denom,
+    ))
+}
+
+/// Parses protobuf representation of Cosmos-SDK **sdk.Coin** generated by **osmosis_std**
+fn parse_osmosis_coin(coin: OsmosisCoin) -> NeutronResult<Coin> {
+    parse_coin(CosmosCoin {
+        denom: coin.denom,
+        amount: coin.amount,
+    })
+}
+
+/// Adds rewards of **stake** accumulated between periods with **starting_ratio** and **ending_ratio**
+/// cumulative reward ratios to **rewards**
+fn add_rewards_between(
+    rewards: &mut BTreeMap<String, Decimal>,
+    starting_ratio: &BTreeMap<String, Decimal>,
+    ending_ratio: &BTreeMap<String, Decimal>,
+    stake: Decimal,
+) -> NeutronResult<()> {
+    for (denom, starting) in starting_ratio {
+        if ending_ratio.get(denom).copied().unwrap_or_default() < *starting {
+            return Err(NeutronError::InvalidQueryResultFormat(
+                "cumulative reward ratio is decreasing".into(),
+            ));
+        }
+    }
+
+    for (denom, ending) in ending_ratio {
+        let starting = starting_ratio.get(denom).copied().unwrap_or_default();
+        let difference = Decimal::new(ending.atomics() - starting.atomics());
+
+        let reward = rewards.entry(denom.clone()).or_default();
+        *reward = checked_add_dec(*reward, difference.checked_mul(stake)?)?;
+    }
+
+    Ok(())
+}
+
+/// Parses protobuf representation of Cosmos-SDK **sdk.DecCoins** into map denom -> amount
+fn parse_dec_coins(coins: &[DecCoin]) -> NeutronResult<BTreeMap<String, Decimal>> {
+    let mut parsed: BTreeMap<String, Decimal> = BTreeMap::new();
+    for coin in coins {
+        parsed.insert(coin.denom.clone(), parse_sdk_dec(&coin.amount)?);
+    }
+
+    Ok(parsed)
+}
+
+/// Truncates decimal amounts of **coins** dropping zero ones, the same way Cosmos-SDK does on rewards withdrawal
+fn truncate_dec_coins(coins: &BTreeMap<String, Decimal>) -> Vec<Coin> {
+    coins
+        .iter()
+        .map(|(denom, amount)| Coin::new(amount.atomics().u128() / DECIMAL_FRACTIONAL, denom))
+        .filter(|coin| !coin.amount.is_zero())
+        .collect()
+}
+
+fn checked_add_dec(a: Decimal, b: Decimal) -> NeutronResult<Decimal> {
+    Ok(Decimal::new(a.atomics().checked_add(b.atomics())?))
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes status of a governance proposal
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/gov/v1beta1/gov.proto
+pub enum ProposalStatus {
+    Unspecified,
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+impl ProposalStatus {
+    /// Converts protobuf representation of proposal status into **ProposalStatus**
+    /// Returns **None** if the value is unknown
+    pub fn from_i32(status: i32) -> Option<ProposalStatus> {
+        match status {
+            0 => Some(ProposalStatus::Unspecified),
+            1 => Some(ProposalStatus::DepositPeriod),
+            2 => Some(ProposalStatus::VotingPeriod),
+            3 => Some(ProposalStatus::Passed),
+            4 => Some(ProposalStatus::Rejected),
+            5 => Some(ProposalStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes tally result of a governance proposal
+pub struct TallyResult {
+    pub yes: Uint128,
+    pub no: Uint128,
+    pub abstain: Uint128,
+    pub no_with_veto: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a governance proposal on remote chain
+pub struct GovProposal {
+    pub proposal_id: u64,
+
+    /// **proposal_type** is a type url of the proposal content, e.g. **/cosmos.gov.v1beta1.TextProposal**
+    pub proposal_type: Option<String>,
+
+    pub status: ProposalStatus,
+
+    /// **final_tally_result** is set when the voting period of the proposal is over
+    pub final_tally_result: Option<TallyResult>,
+
+    pub submit_time: Option<Timestamp>,
+    pub deposit_end_time: Option<Timestamp>,
+    pub total_deposit: Vec<Coin>,
+    pub voting_start_time: Option<Timestamp>,
+    pub voting_end_time: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Gov Proposals Interchain Query**.
+/// Contains governance proposals, keys of proposals which don't exist on remote chain are listed in **absent_keys**.
+pub struct GovProposals {
+    pub proposals: Vec<GovProposal>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl KVReconstruct for GovProposals {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovProposals> {
+        let mut proposals: Vec<GovProposal> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut proposals_ids: BTreeSet<u64> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is PROPOSALS_KEY_PREFIX | proposal_id
+            let rest = strip_key_prefix(kv, GOV_STORE_KEY, PROPOSALS_KEY_PREFIX, "proposal")?;
+            let (proposal_id, rest) = split_key_id(rest, "proposal")?;
+            check_key_end(rest, "proposal")?;
+
+            if !proposals_ids.insert(proposal_id) {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "duplicate proposal {}",
+                    proposal_id
+                )));
+            }
+
+            if kv.value.is_empty() {
+                // the proposal doesn't exist on remote chain
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let proposal: Proposal = Proposal::decode(kv.value.as_slice())?;
+            if proposal.proposal_id != proposal_id {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "proposal {} is stored under the key of proposal {}",
+                    proposal.proposal_id, proposal_id
+                )));
+            }
+
+            let status = ProposalStatus::from_i32(proposal.status).ok_or_else(|| {
+                NeutronError::InvalidQueryResultFormat(format!(
+                    "invalid proposal status: {}",
+                    proposal.status
+                ))
+            })?;
+
+            let final_tally_result = match proposal.final_tally_result {
+                Some(tally) => Some(TallyResult {
+                    yes: Uint128::from_str(&tally.yes)?,
+                    no: Uint128::from_str(&tally.no)?,
+                    abstain: Uint128::from_str(&tally.abstain)?,
+                    no_with_veto: Uint128::from_str(&tally.no_with_veto)?,
+                }),
+                None => None,
+            };
+
+            proposals.push(GovProposal {
+                proposal_id: proposal.proposal_id,
+                proposal_type: proposal.content.map(|content| content.type_url),
+                status,
+                final_tally_result,
+                submit_time: proposal
+                    .submit_time
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+                deposit_end_time: proposal
+                    .deposit_end_time
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+                total_deposit: parse_coins(proposal.total_deposit)?,
+                voting_start_time: proposal
+                    .voting_start_time
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+                voting_end_time: proposal
+                    .voting_end_time
+                    .map(|t| proto_timestamp(t.seconds, t.nanos))
+                    .transpose()?,
+            })
+        }
+
+        Ok(GovProposals {
+            proposals,
+            absent_keys,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes a vote option of a governance proposal
+/// https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/proto/cosmos/gov/v1beta1/gov.proto
+pub enum VoteOption {
+    Unspecified,
+    Yes,
+    Abstain,
+    No,
+    NoWithVeto,
+}
+
+impl VoteOption {
+    /// Converts protobuf representation of vote option into **VoteOption**
+    /// Returns **None** if the value is unknown
+    pub fn from_i32(option: i32) -> Option<VoteOption> {
+        match option {
+            0 => Some(VoteOption::Unspecified),
+            1 => Some(VoteOption::Yes),
+            2 => Some(VoteOption::Abstain),
+            3 => Some(VoteOption::No),
+            4 => Some(VoteOption::NoWithVeto),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a vote option with its weight (votes can be split between several options)
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+    pub weight: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// Describes a vote of a voter for a governance proposal
+pub struct ProposalVote {
+    pub proposal_id: u64,
+    pub voter: String,
+    pub options: Vec<WeightedVoteOption>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+/// A structure that can be reconstructed from **StorageValues**'s for the **Gov Proposal Votes Interchain Query**.
+/// Contains votes for a governance proposal, keys of voters which haven't voted are listed in **absent_keys**.
+pub struct GovProposalVotes {
+    pub votes: Vec<ProposalVote>,
+
+    /// **absent_keys** is a list of keys of the query which have no value on remote chain
+    pub absent_keys: Vec<KVKey>,
+}
+
+impl KVReconstruct for GovProposalVotes {
+    fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<GovProposalVotes> {
+        let mut votes: Vec<ProposalVote> = Vec::with_capacity(storage_values.len());
+        let mut absent_keys: Vec<KVKey> = vec![];
+        let mut proposal: Option<u64> = None;
+        let mut voters: BTreeSet<&[u8]> = BTreeSet::new();
+
+        for kv in storage_values {
+            // the key is VOTES_KEY_PREFIX | proposal_id | len(voter) | voter
+            let rest = strip_key_prefix(kv, GOV_STORE_KEY, VOTES_KEY_PREFIX, "proposal vote")?;
+            let (proposal_id, rest) = split_key_id(rest, "proposal vote")?;
+            let (voter_addr, rest) = decode_length_prefixed(rest)?;
+            check_key_end(rest, "proposal vote")?;
+
+            match proposal {
+                None => proposal = Some(proposal_id),
+                Some(proposal) if proposal != proposal_id => {
+                    return Err(NeutronError::InvalidQueryResultFormat(
+                        "votes for different proposals".into(),
+                    ))
+                }
+                _ => {}
+            }
+
+            if !voters.insert(voter_addr) {
+                return Err(NeutronError::InvalidQueryResultFormat(
+                    "duplicate proposal vote".into(),
+                ));
+            }
+
+            if kv.value.is_empty() {
+                // the voter hasn't voted for the proposal
+                absent_keys.push(storage_value_key(kv));
+                continue;
+            }
+
+            let vote: Vote = Vote::decode(kv.value.as_slice())?;
+            if vote.proposal_id != proposal_id {
+                return Err(NeutronError::InvalidQueryResultFormat(format!(
+                    "vote for proposal {} is stored under the key of proposal {}",
+                    vote.proposal_id, proposal_id
+                )));
+            }
+            check_key_address(&vote.voter, voter_addr, "voter")?;
+
+            let parse_option = |option: i32| {
+                VoteOption::from_i32(option).ok_or_else(|| {
+                    NeutronError::InvalidQueryResultFormat(format!(
+                        "invalid vote option: {}",
+                        option
+                    ))
+                })
+            };
+
+            let mut options: Vec<WeightedVoteOption> = Vec::with_capacity(vote.options.len());
+            for option in vote.options {
+                options.push(WeightedVoteOption {
+                    option: parse_option(option.option)?,
+                    weight: parse_sdk_dec(&option.weight)?,
+                })
+            }
+            // votes stored before weighted voting was introduced have only a single option
+            #[allow(deprecated)]
+            let legacy_option = vote.option;
+            if options.is_empty() && legacy_option != 0 {
+                options.push(WeightedVoteOption {
+                    option: parse_option(legacy_option)?,
+                    weight: Decimal::one(),
+                })
+            }
+
+            votes.push(ProposalVote {
+                proposal_id: vote.proposal_id,
+                voter: vote.voter,
+                options,
+            })
+        }
+
+        Ok(GovProposalVotes { votes, absent_keys })
+    }
+}
+
+/// Checks that **kv** is read from the **store** by a key starting with **prefix**, returns the rest of the key
+fn strip_key_prefix<'a>(
+    kv: &'a StorageValue,
+    store: &str,
+    prefix: u8,
+    name: &str,
+) -> NeutronResult<&'a [u8]> {
+    check_storage_prefix(kv, store)?;
+
+    match kv.key.as_slice().split_first() {
+        Some((&key_prefix, rest)) if key_prefix == prefix => Ok(rest),
+        _ => Err(NeutronError::InvalidQueryResultFormat(format!(
+            "unexpected {} key",
+            name
+        ))),
+    }
+}
+
+/// Checks that nothing is left in the **rest** of a key after its parts are parsed
+fn check_key_end(rest: &[u8], name: &str) -> NeutronResult<()> {
+    if !rest.is_empty() {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "invalid {} key",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks that bech32 encoded **address** from a storage value is the address **addr** from its key
+fn check_key_address(address: &str, addr: &[u8], name: &str) -> NeutronResult<()> {
+    if decode_and_convert(address)? != addr {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "{} {} is stored under the key of another address",
+            name, address
+        )));
+    }
+
+    Ok(())
+}
+
+/// Parses big endian encoded id (of a proposal, a pool, etc.) from the beginning of a store key,
+/// returns the id and the rest of the key
+fn split_key_id<'a>(key: &'a [u8], name: &str) -> NeutronResult<(u64, &'a [u8])> {
+    if key.len() < 8 {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "invalid {} key",
+            name
+        )));
+    }
+
+    let (id, rest) = key.split_at(8);
+    let mut id_bytes = [0u8; 8];
+    id_bytes.copy_from_slice(id);
+
+    Ok((u64::from_be_bytes(id_bytes), rest))
+}
+
+/// Returns the key storage value **kv** is read by
+fn storage_value_key(kv: &StorageValue) -> KVKey {
+    KVKey {
+        path: kv.storage_prefix.clone(),
+        key: kv.key.clone(),
+    }
+}
+
+/// Parses protobuf representation of Cosmos-SDK **sdk.Dec** (integer string with 18 decimal places) into **Decimal**
+fn parse_sdk_dec(dec: &str) -> NeutronResult<Decimal> {
+    Ok(Decimal::from_atomics(
+        Uint128::from_str(dec)?,
+        DECIMAL_PLACES,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bindings::types::{KVKey, ProtobufAny, StorageValue};
+    use crate::interchain_queries::helpers::{
+        create_account_denom_balance_key, create_account_key, create_authz_grant_key,
+        create_cw_storage_map_key, create_delegation_key, create_delegator_starting_info_key,
+        create_denom_metadata_key, create_denom_trace_key, create_fee_allowance_key,
+        create_gamm_pool_key, create_gov_proposal_key, create_gov_proposal_voter_votes_key,
+        create_gov_proposal_votes_key, create_params_store_key, create_redelegation_key,
+        create_total_denom_key, create_unbonding_delegation_key,
+        create_validator_current_rewards_key, create_validator_historical_rewards_key,
+        create_validator_key, create_validator_signing_info_key, create_validator_slash_event_key,
+        create_wasm_contract_store_key, decode_and_convert, decode_ibc_denom_hash,
+        encode_and_convert, get_ibc_denom,
+    };
+    use crate::interchain_queries::types::{
+        Account, AccountInfo, Authorization, AuthzGrant, AuthzGrants, Balances, BondStatus,
+        CosmosAllowedMsgAllowance, CosmosAuthzGrant, CosmosBasicAllowance, CosmosFeeGrant,
+        CosmosGenericAuthorization, CosmosPeriodicAllowance, CosmosStakeAuthorization,
+        CosmosStakeAuthorizationValidators, DelegationRewards, Delegations, DenomMetadata,
+        DenomMetadataInfo, DenomTrace, DenomTraces, DenomUnit, FeeAllowance, FeeGrantAllowance,
+        GammPool, GammPoolAsset, GammPoolType, GovProposal, GovProposalVotes, GovProposals,
+        IbcDenomTrace, KVReconstruct, PendingRewards, PeriodicFeeAllowance, ProposalStatus,
+        ProposalVote, RawKv, Redelegation, RedelegationEntry, Redelegations, SigningInfo,
+        StakeAuthorizationType, StakingValidator, StakingValidators, TallyResult, TotalSupply,
+        TransactionFilterItem, TransactionFilterOp, TransactionFilterValue, TxFilter,
+        UnbondingDelegation, UnbondingDelegationEntry, UnbondingDelegations, ValidatorCommission,
+        ValidatorDescription, ValidatorSigningInfo, VestingKind, VestingSchedule, VoteOption,
+        WasmContractState, WeightedVoteOption, ACCOUNT_ADDRESS_PREFIX, ACCOUNT_STORE_KEY,
+        AUTHZ_STORE_KEY, BANK_STORE_KEY, DISTRIBUTION_STORE_KEY, ED25519_PUBKEY_TYPE_URL,
+        FEEGRANT_STORE_KEY, GAMM_BALANCER_POOL_TYPE_URL, GAMM_STABLESWAP_POOL_TYPE_URL,
+        GAMM_STORE_KEY, GOV_STORE_KEY, HEIGHT_FIELD, KEY_BOND_DENOM, PARAMS_STORE_KEY,
+        RECIPIENT_FIELD, SLASHING_STORE_KEY, STAKING_STORE_KEY, TRANSFER_STORE_KEY,
+        WASM_CONTRACT_STORE_PREFIX, WASM_STORE_KEY,
+    };
+    use crate::{NeutronError, NeutronResult};
+    use cosmos_sdk_proto::cosmos::auth::v1beta1::BaseAccount;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::{DenomUnit as CosmosDenomUnit, Metadata};
+    use cosmos_sdk_proto::cosmos::base::v1beta1::{Coin, DecCoin};
+    use cosmos_sdk_proto::cosmos::crypto::ed25519::PubKey;
+    use cosmos_sdk_proto::cosmos::distribution::v1beta1::{
+        DelegatorStartingInfo, ValidatorCurrentRewards, ValidatorHistoricalRewards,
+        ValidatorSlashEvent,
+    };
+    use cosmos_sdk_proto::cosmos::gov::v1beta1::{
+        Proposal, TallyResult as CosmosTallyResult, Vote,
+        WeightedVoteOption as CosmosWeightedVoteOption,
+    };
+    use cosmos_sdk_proto::cosmos::slashing::v1beta1::ValidatorSigningInfo as CosmosValidatorSigningInfo;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+        Commission, CommissionRates, Delegation, Description, Redelegation as CosmosRedelegation,
+        RedelegationEntry as CosmosRedelegationEntry,
+        UnbondingDelegation as CosmosUnbondingDelegation,
+        UnbondingDelegationEntry as CosmosUnbondingDelegationEntry, Validator,
+    };
+    use cosmos_sdk_proto::cosmos::vesting::v1beta1::{
+        BaseVestingAccount, ContinuousVestingAccount,
+    };
+    use cosmwasm_std::{
+        to_binary, Addr, Binary, Coin as StdCoin, Decimal, Delegation as StdDelegation,
+        OverflowError, OverflowOperation, Timestamp, Uint128,
+    };
+    use osmosis_std::types::cosmos::base::v1beta1::Coin as OsmosisCoin;
+    use osmosis_std::types::osmosis::gamm::poolmodels::stableswap::v1beta1::{
+        Pool as OsmosisStableswapPool, PoolParams as OsmosisStableswapPoolParams,
+    };
+    use osmosis_std::types::osmosis::gamm::v1beta1::{
+        Pool as OsmosisBalancerPool, PoolAsset as OsmosisBalancerPoolAsset,
+        PoolParams as OsmosisBalancerPoolParams,
+    };
+    use prost::Message as ProstMessage;
+    use serde::{Deserialize, Serialize};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_balance_reconstruct() {
+        struct TestCase {
+            addr: String,
+            coins: Vec<(String, Uint128)>,
+        }
+        let test_cases: Vec<TestCase> = vec![
+            TestCase {
+                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                coins: vec![("uosmo".to_string(), Uint128::from(100u128))],
+            },
+            TestCase {
+                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                coins: vec![
+                    ("uosmo".to_string(), Uint128::from(100u128)),
+                    ("uatom".to_string(), Uint128::from(500u128)),
+                    ("uluna".to_string(), Uint128::from(80u128)),
+                ],
+            },
+            TestCase {
+                addr: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                coins: vec![],
+            },
+        ];
+
+        for ts in test_cases.iter() {
+            let mut st_values: Vec<StorageValue> = vec![];
+
+            let converted_addr_bytes = decode_and_convert(ts.addr.as_str()).unwrap();
+            for coin in &ts.coins {
+                let balance_key =
+                    create_account_denom_balance_key(converted_addr_bytes.clone(), &coin.0)
+                        .unwrap();
+
+                let balance_amount = Coin {
+                    denom: coin.0.clone(),
+                    amount: coin.1.to_string(),
+                };
+                let s = StorageValue {
+                    storage_prefix: BANK_STORE_KEY.to_string(),
                     key: Binary(balance_key),
                     value: Binary(balance_amount.encode_to_vec()),
                 };
                 st_values.push(s);
             }
 
-            let balances = Balances::reconstruct(&st_values).unwrap();
-            assert_eq!(balances.coins.len(), ts.coins.len());
-            for (i, coin) in balances.coins.iter().enumerate() {
-                assert_eq!(coin.denom, ts.coins[i].0);
-                assert_eq!(coin.amount, ts.coins[i].1)
+            let balances = Balances::reconstruct(&st_values).unwrap();
+            assert_eq!(balances.coins.len(), ts.coins.len());
+            for (i, coin) in balances.coins.iter().enumerate() {
+                assert_eq!(coin.denom, ts.coins[i].0);
+                assert_eq!(coin.amount, ts.coins[i].1)
+            }
+        }
+    }
+
+    #[test]
+    fn test_balance_reconstruct_checks_keys() {
+        let addr = decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+        let other_addr =
+            decode_and_convert("osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t")
+                .unwrap();
+        let balance_value =
+            |addr: &[u8], key_denom: &str, denom: &str, amount: &str| StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(create_account_denom_balance_key(addr, key_denom).unwrap()),
+                value: Binary(
+                    Coin {
+                        denom: denom.to_string(),
+                        amount: amount.to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
+            };
+
+        // the balance doesn't exist on remote chain
+        let mut absent = balance_value(&addr, "uatom", "", "");
+        absent.value = Binary::default();
+        let balances =
+            Balances::reconstruct(&[balance_value(&addr, "uosmo", "uosmo", "100"), absent])
+                .unwrap();
+        assert_eq!(
+            balances.coins,
+            vec![StdCoin::new(100, "uosmo"), StdCoin::new(0, "uatom")]
+        );
+
+        // the value doesn't match the key
+        assert!(Balances::reconstruct(&[balance_value(&addr, "uosmo", "uatom", "100")]).is_err());
+
+        // balances of different accounts
+        assert!(Balances::reconstruct(&[
+            balance_value(&addr, "uosmo", "uosmo", "100"),
+            balance_value(&other_addr, "uatom", "uatom", "100"),
+        ])
+        .is_err());
+
+        // duplicate balances
+        assert!(Balances::reconstruct(&[
+            balance_value(&addr, "uosmo", "uosmo", "100"),
+            balance_value(&addr, "uosmo", "uosmo", "200"),
+        ])
+        .is_err());
+
+        // the value is read from a wrong store
+        let mut wrong_store = balance_value(&addr, "uosmo", "uosmo", "100");
+        wrong_store.storage_prefix = STAKING_STORE_KEY.to_string();
+        assert!(Balances::reconstruct(&[wrong_store]).is_err());
+
+        // the key is not a balance key
+        let mut wrong_key = balance_value(&addr, "uosmo", "uosmo", "100");
+        wrong_key.key = Binary(create_total_denom_key("uosmo"));
+        assert!(Balances::reconstruct(&[wrong_key]).is_err());
+    }
+
+    #[test]
+    fn test_delegations_reconstruct() {
+        struct TestCase {
+            stake_denom: String,
+            delegations: Vec<Delegation>,
+            validators: Vec<Validator>,
+            expected_result: NeutronResult<Delegations>,
+        }
+        let test_cases: Vec<TestCase> = vec![
+            TestCase {
+                stake_denom: "stake".to_string(),
+                delegations: vec![Delegation {
+                    delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                    validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                        .to_string(),
+                    shares: "1000000000000000000".to_string(),
+                }],
+                validators: vec![Validator {
+                    operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                        .to_string(),
+                    consensus_pubkey: None,
+                    jailed: false,
+                    status: 0,
+                    tokens: "1000000000000000000".to_string(),
+                    delegator_shares: "1000000000000000000".to_string(),
+                    description: None,
+                    unbonding_height: 0,
+                    unbonding_time: None,
+                    commission: None,
+                    min_self_delegation: "".to_string(),
+                }],
+                expected_result: Ok(Delegations {
+                    delegations: vec![StdDelegation {
+                        delegator: Addr::unchecked("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"),
+                        validator: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+                        amount: StdCoin::new(1000000000000000000u128, "stake"),
+                    }],
+                }),
+            },
+            TestCase {
+                stake_denom: "stake".to_string(),
+                delegations: vec![
+                    Delegation {
+                        delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"
+                            .to_string(),
+                        validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                            .to_string(),
+                        shares: "1000000000000000000".to_string(),
+                    },
+                    Delegation {
+                        delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"
+                            .to_string(),
+                        validator_address: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
+                            .to_string(),
+                        shares: "1000000000000000000".to_string(),
+                    },
+                ],
+                validators: vec![
+                    Validator {
+                        operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                            .to_string(),
+                        consensus_pubkey: None,
+                        jailed: false,
+                        status: 0,
+                        tokens: "1000000000000000000".to_string(),
+                        delegator_shares: "1000000000000000000".to_string(),
+                        description: None,
+                        unbonding_height: 0,
+                        unbonding_time: None,
+                        commission: None,
+                        min_self_delegation: "".to_string(),
+                    },
+                    Validator {
+                        operator_address: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
+                            .to_string(),
+                        consensus_pubkey: None,
+                        jailed: false,
+                        status: 0,
+                        tokens: "1000000000000000000".to_string(),
+                        delegator_shares: "1000000000000000000".to_string(),
+                        description: None,
+                        unbonding_height: 0,
+                        unbonding_time: None,
+                        commission: None,
+                        min_self_delegation: "".to_string(),
+                    },
+                ],
+                expected_result: Ok(Delegations {
+                    delegations: vec![
+                        StdDelegation {
+                            delegator: Addr::unchecked(
+                                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs",
+                            ),
+                            validator: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                                .to_string(),
+                            amount: StdCoin::new(1000000000000000000u128, "stake"),
+                        },
+                        StdDelegation {
+                            delegator: Addr::unchecked(
+                                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs",
+                            ),
+                            validator: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
+                                .to_string(),
+                            amount: StdCoin::new(1000000000000000000u128, "stake"),
+                        },
+                    ],
+                }),
+            },
+            TestCase {
+                stake_denom: "stake".to_string(),
+                delegations: vec![],
+                validators: vec![],
+                expected_result: Ok(Delegations {
+                    delegations: vec![],
+                }),
+            },
+            TestCase {
+                stake_denom: Default::default(),
+                delegations: vec![],
+                validators: vec![],
+                expected_result: Err(NeutronError::InvalidQueryResultFormat(
+                    "denom is empty".into(),
+                )),
+            },
+            TestCase {
+                stake_denom: "stake".to_string(),
+                delegations: vec![Delegation {
+                    delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                    validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                        .to_string(),
+                    shares: "1000000000000000000".to_string(),
+                }],
+                validators: vec![],
+                expected_result: Err(NeutronError::InvalidQueryResultFormat(
+                    "validator is empty".into(),
+                )),
+            },
+        ];
+
+        for ts in &test_cases {
+            // prepare storage values
+            let mut st_values: Vec<StorageValue> = vec![StorageValue {
+                storage_prefix: PARAMS_STORE_KEY.to_string(),
+                key: Binary(create_params_store_key(STAKING_STORE_KEY, KEY_BOND_DENOM)),
+                value: {
+                    if ts.stake_denom.is_empty() {
+                        Binary::default()
+                    } else {
+                        to_binary(&ts.stake_denom).unwrap()
+                    }
+                },
+            }];
+
+            for (i, d) in ts.delegations.iter().enumerate() {
+                let delegator_addr = decode_and_convert(&d.delegator_address).unwrap();
+                let val_addr = decode_and_convert(&d.validator_address).unwrap();
+
+                st_values.push(StorageValue {
+                    storage_prefix: STAKING_STORE_KEY.to_string(),
+                    key: Binary(create_delegation_key(&delegator_addr, &val_addr).unwrap()),
+                    value: Binary::from(d.encode_to_vec()),
+                });
+
+                st_values.push(StorageValue {
+                    storage_prefix: STAKING_STORE_KEY.to_string(),
+                    key: Binary(create_validator_key(&val_addr).unwrap()),
+                    value: ts
+                        .validators
+                        .get(i)
+                        .map(|v| Binary::from(v.encode_to_vec()))
+                        .unwrap_or_default(),
+                });
+            }
+
+            // test reconstruction
+            let delegations = Delegations::reconstruct(&st_values);
+
+            assert_eq!(delegations, ts.expected_result);
+
+            // delegations are matched with validators by the keys, not by the order
+            if ts.expected_result.is_ok() {
+                st_values[1..].reverse();
+                assert_eq!(Delegations::reconstruct(&st_values), ts.expected_result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_delegations_reconstruct_checks_keys() {
+        let delegator_addr =
+            decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+        let val_addr =
+            decode_and_convert("osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3").unwrap();
+        let other_delegator_addr =
+            decode_and_convert("osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t")
+                .unwrap();
+
+        let denom_value = StorageValue {
+            storage_prefix: PARAMS_STORE_KEY.to_string(),
+            key: Binary(create_params_store_key(STAKING_STORE_KEY, KEY_BOND_DENOM)),
+            value: to_binary("stake").unwrap(),
+        };
+        let delegation_value = StorageValue {
+            storage_prefix: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_delegation_key(&delegator_addr, &val_addr).unwrap()),
+            value: Binary::from(
+                Delegation {
+                    delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+                    validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                        .to_string(),
+                    shares: "1000000000000000000".to_string(),
+                }
+                .encode_to_vec(),
+            ),
+        };
+        let validator = Validator {
+            operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            tokens: "1000000000000000000".to_string(),
+            delegator_shares: "1000000000000000000".to_string(),
+            ..Default::default()
+        };
+        let validator_value = StorageValue {
+            storage_prefix: STAKING_STORE_KEY.to_string(),
+            key: Binary(create_validator_key(&val_addr).unwrap()),
+            value: Binary::from(validator.encode_to_vec()),
+        };
+
+        // no values at all
+        assert_eq!(
+            Delegations::reconstruct(&[]),
+            Err(NeutronError::InvalidQueryResultFormat(
+                "denom is missing".into()
+            ))
+        );
+
+        // the first value is not the denom
+        assert!(Delegations::reconstruct(&[
+            delegation_value.clone(),
+            denom_value.clone(),
+            validator_value.clone(),
+        ])
+        .is_err());
+
+        // the validator of the delegation is missing
+        assert_eq!(
+            Delegations::reconstruct(&[denom_value.clone(), delegation_value.clone()]),
+            Err(NeutronError::InvalidQueryResultFormat(
+                "validator is missing".into()
+            ))
+        );
+
+        // the delegation doesn't exist on remote chain, its validator is skipped
+        let mut absent_delegation = delegation_value.clone();
+        absent_delegation.value = Binary::default();
+        assert_eq!(
+            Delegations::reconstruct(&[
+                denom_value.clone(),
+                absent_delegation,
+                validator_value.clone(),
+            ]),
+            Ok(Delegations {
+                delegations: vec![]
+            })
+        );
+
+        // delegations of different delegators
+        let mut other_delegation = delegation_value.clone();
+        other_delegation.key =
+            Binary(create_delegation_key(&other_delegator_addr, &val_addr).unwrap());
+        assert!(Delegations::reconstruct(&[
+            denom_value.clone(),
+            delegation_value.clone(),
+            validator_value.clone(),
+            other_delegation,
+            validator_value.clone(),
+        ])
+        .is_err());
+
+        // the value is read from a wrong store
+        let mut wrong_store = validator_value.clone();
+        wrong_store.storage_prefix = DISTRIBUTION_STORE_KEY.to_string();
+        assert!(Delegations::reconstruct(&[
+            denom_value.clone(),
+            delegation_value.clone(),
+            wrong_store,
+        ])
+        .is_err());
+
+        // the validator has no shares
+        let mut no_shares_validator = validator_value;
+        no_shares_validator.value = Binary::from(
+            Validator {
+                delegator_shares: "0".to_string(),
+                ..validator
+            }
+            .encode_to_vec(),
+        );
+        assert!(
+            Delegations::reconstruct(&[denom_value, delegation_value, no_shares_validator,])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_total_supply_reconstruct() {
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(create_total_denom_key("uatom")),
+                value: Binary::from("272861394029".as_bytes()),
+            },
+            // denom doesn't exist on remote chain
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(create_total_denom_key("unknown")),
+                value: Binary::default(),
+            },
+        ];
+
+        let supply = TotalSupply::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            supply,
+            TotalSupply {
+                coins: vec![
+                    StdCoin::new(272861394029u128, "uatom"),
+                    StdCoin::new(0u128, "unknown")
+                ]
+            }
+        );
+
+        let invalid_amount = vec![StorageValue {
+            storage_prefix: BANK_STORE_KEY.to_string(),
+            key: Binary(create_total_denom_key("uatom")),
+            value: Binary::from("not a number".as_bytes()),
+        }];
+        assert!(TotalSupply::reconstruct(&invalid_amount).is_err());
+    }
+
+    #[test]
+    fn test_denom_metadata_reconstruct() {
+        let metadata = Metadata {
+            description: "The native staking token of the Cosmos Hub.".to_string(),
+            denom_units: vec![
+                CosmosDenomUnit {
+                    denom: "uatom".to_string(),
+                    exponent: 0,
+                    aliases: vec!["microatom".to_string()],
+                },
+                CosmosDenomUnit {
+                    denom: "atom".to_string(),
+                    exponent: 6,
+                    aliases: vec![],
+                },
+            ],
+            base: "uatom".to_string(),
+            display: "atom".to_string(),
+            name: "Cosmos Hub Atom".to_string(),
+            symbol: "ATOM".to_string(),
+        };
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(create_denom_metadata_key("uatom")),
+                value: Binary::from(metadata.encode_to_vec()),
+            },
+            // metadata is not set for the denom on remote chain
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(create_denom_metadata_key("unknown")),
+                value: Binary::default(),
+            },
+        ];
+
+        let denom_metadata = DenomMetadata::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            denom_metadata,
+            DenomMetadata {
+                metadata: vec![DenomMetadataInfo {
+                    description: "The native staking token of the Cosmos Hub.".to_string(),
+                    denom_units: vec![
+                        DenomUnit {
+                            denom: "uatom".to_string(),
+                            exponent: 0,
+                            aliases: vec!["microatom".to_string()],
+                        },
+                        DenomUnit {
+                            denom: "atom".to_string(),
+                            exponent: 6,
+                            aliases: vec![],
+                        },
+                    ],
+                    base: "uatom".to_string(),
+                    display: "atom".to_string(),
+                    name: "Cosmos Hub Atom".to_string(),
+                    symbol: "ATOM".to_string(),
+                }]
+            }
+        );
+        assert_eq!(denom_metadata.metadata[0].decimals(), Some(6));
+    }
+
+    #[test]
+    fn test_staking_validators_reconstruct() {
+        let validator = Validator {
+            operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            consensus_pubkey: Some(prost_types::Any {
+                type_url: "/cosmos.crypto.ed25519.PubKey".to_string(),
+                value: vec![10, 2, 1, 2],
+            }),
+            jailed: true,
+            status: 2,
+            tokens: "1000000".to_string(),
+            delegator_shares: "1000000000000000000000000".to_string(),
+            description: Some(Description {
+                moniker: "validator".to_string(),
+                identity: "".to_string(),
+                website: "https://validator.example".to_string(),
+                security_contact: "".to_string(),
+                details: "".to_string(),
+            }),
+            unbonding_height: 100,
+            unbonding_time: Some(prost_types::Timestamp {
+                seconds: 1_650_000_000,
+                nanos: 0,
+            }),
+            commission: Some(Commission {
+                commission_rates: Some(CommissionRates {
+                    rate: "50000000000000000".to_string(),
+                    max_rate: "200000000000000000".to_string(),
+                    max_change_rate: "10000000000000000".to_string(),
+                }),
+                update_time: None,
+            }),
+            min_self_delegation: "1".to_string(),
+        };
+        let val_addr = decode_and_convert(&validator.operator_address).unwrap();
+        let absent_val_addr = vec![1u8; 20];
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_validator_key(&val_addr).unwrap()),
+                value: Binary::from(validator.encode_to_vec()),
+            },
+            // validator doesn't exist on remote chain
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_validator_key(&absent_val_addr).unwrap()),
+                value: Binary::default(),
+            },
+        ];
+
+        let validators = StakingValidators::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            validators,
+            StakingValidators {
+                validators: vec![StakingValidator {
+                    operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                        .to_string(),
+                    consensus_pubkey: Some(ProtobufAny::new(
+                        "/cosmos.crypto.ed25519.PubKey".to_string(),
+                        Binary::from(vec![10, 2, 1, 2]),
+                    )),
+                    jailed: true,
+                    status: BondStatus::Unbonding,
+                    tokens: Uint128::new(1_000_000),
+                    delegator_shares: Decimal::from_str("1000000").unwrap(),
+                    description: Some(ValidatorDescription {
+                        moniker: "validator".to_string(),
+                        identity: "".to_string(),
+                        website: "https://validator.example".to_string(),
+                        security_contact: "".to_string(),
+                        details: "".to_string(),
+                    }),
+                    unbonding_height: 100,
+                    unbonding_time: Some(Timestamp::from_seconds(1_650_000_000)),
+                    commission: Some(ValidatorCommission {
+                        rate: Decimal::percent(5),
+                        max_rate: Decimal::percent(20),
+                        max_change_rate: Decimal::percent(1),
+                        update_time: None,
+                    }),
+                    min_self_delegation: Uint128::new(1),
+                }],
+                absent_keys: vec![KVKey {
+                    path: STAKING_STORE_KEY.to_string(),
+                    key: Binary(create_validator_key(&absent_val_addr).unwrap()),
+                }],
+            }
+        );
+
+        // the validator is stored under the key of another validator
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key = Binary(create_validator_key(&absent_val_addr).unwrap());
+        assert_eq!(
+            StakingValidators::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "validator osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3 is stored under the key of another address"
+                    .to_string()
+            )
+        );
+
+        // the same validator twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            StakingValidators::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate validator".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values.clone();
+        unexpected_key[1].key = Binary(create_delegation_key(&val_addr, &val_addr).unwrap());
+        assert_eq!(
+            StakingValidators::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("unexpected validator key".to_string())
+        );
+
+        // trailing bytes in the key
+        let mut long_key = st_values.clone();
+        long_key[1].key.0.push(0);
+        assert_eq!(
+            StakingValidators::reconstruct(&long_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid validator key".to_string())
+        );
+
+        // a value of another store
+        let mut wrong_store = st_values;
+        wrong_store[1].storage_prefix = BANK_STORE_KEY.to_string();
+        assert_eq!(
+            StakingValidators::reconstruct(&wrong_store).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from staking store, got bank".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_unbonding_delegations_reconstruct() {
+        let delegator = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+        let validator = "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string();
+        let del_addr = decode_and_convert(&delegator).unwrap();
+        let val_addr = decode_and_convert(&validator).unwrap();
+        let absent_val_addr = vec![1u8; 20];
+
+        let ubd = CosmosUnbondingDelegation {
+            delegator_address: delegator.clone(),
+            validator_address: validator.clone(),
+            entries: vec![
+                CosmosUnbondingDelegationEntry {
+                    creation_height: 100,
+                    completion_time: Some(prost_types::Timestamp {
+                        seconds: 1_650_000_000,
+                        nanos: 0,
+                    }),
+                    initial_balance: "1000".to_string(),
+                    balance: "900".to_string(),
+                },
+                CosmosUnbondingDelegationEntry {
+                    creation_height: 200,
+                    completion_time: Some(prost_types::Timestamp {
+                        seconds: 1_660_000_000,
+                        nanos: 0,
+                    }),
+                    initial_balance: "500".to_string(),
+                    balance: "500".to_string(),
+                },
+            ],
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_unbonding_delegation_key(&del_addr, &val_addr).unwrap()),
+                value: Binary::from(ubd.encode_to_vec()),
+            },
+            // the delegator has no unbonding delegations from the validator
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_unbonding_delegation_key(&del_addr, &absent_val_addr).unwrap()),
+                value: Binary::default(),
+            },
+        ];
+
+        let unbonding_delegations = UnbondingDelegations::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            unbonding_delegations,
+            UnbondingDelegations {
+                unbonding_responses: vec![UnbondingDelegation {
+                    delegator_address: delegator,
+                    validator_address: validator,
+                    entries: vec![
+                        UnbondingDelegationEntry {
+                            creation_height: 100,
+                            completion_time: Some(Timestamp::from_seconds(1_650_000_000)),
+                            initial_balance: Uint128::new(1000),
+                            balance: Uint128::new(900),
+                        },
+                        UnbondingDelegationEntry {
+                            creation_height: 200,
+                            completion_time: Some(Timestamp::from_seconds(1_660_000_000)),
+                            initial_balance: Uint128::new(500),
+                            balance: Uint128::new(500),
+                        },
+                    ],
+                }],
+                absent_keys: vec![KVKey {
+                    path: STAKING_STORE_KEY.to_string(),
+                    key: Binary(
+                        create_unbonding_delegation_key(&del_addr, &absent_val_addr).unwrap()
+                    ),
+                }],
+            }
+        );
+
+        // the unbonding delegation is stored under the key of another validator
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key =
+            Binary(create_unbonding_delegation_key(&del_addr, &absent_val_addr).unwrap());
+        assert_eq!(
+            UnbondingDelegations::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "validator {} is stored under the key of another address",
+                validator
+            ))
+        );
+
+        // unbonding delegations of another delegator
+        let mut other_delegator = st_values.clone();
+        other_delegator[1].key =
+            Binary(create_unbonding_delegation_key(&absent_val_addr, &absent_val_addr).unwrap());
+        assert_eq!(
+            UnbondingDelegations::reconstruct(&other_delegator).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "unbonding delegations of different delegators".to_string()
+            )
+        );
+
+        // the same validator twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            UnbondingDelegations::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate unbonding delegation".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values.clone();
+        unexpected_key[1].key = Binary(create_delegation_key(&del_addr, &val_addr).unwrap());
+        assert_eq!(
+            UnbondingDelegations::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "unexpected unbonding delegation key".to_string()
+            )
+        );
+
+        assert_eq!(
+            unbonding_delegations
+                .completed_amount(Timestamp::from_seconds(1_600_000_000))
+                .unwrap(),
+            Uint128::zero()
+        );
+        assert_eq!(
+            unbonding_delegations
+                .completed_amount(Timestamp::from_seconds(1_650_000_000))
+                .unwrap(),
+            Uint128::new(900)
+        );
+        assert_eq!(
+            unbonding_delegations
+                .completed_amount(Timestamp::from_seconds(1_700_000_000))
+                .unwrap(),
+            Uint128::new(1400)
+        );
+
+        // the sum of the balances overflows
+        let mut overflowing = unbonding_delegations.clone();
+        overflowing.unbonding_responses[0].entries[1].balance = Uint128::MAX;
+        assert_eq!(
+            overflowing
+                .completed_amount(Timestamp::from_seconds(1_700_000_000))
+                .unwrap_err(),
+            NeutronError::OverflowError(OverflowError::new(
+                OverflowOperation::Add,
+                Uint128::new(900),
+                Uint128::MAX
+            ))
+        );
+    }
+
+    #[test]
+    fn test_redelegations_reconstruct() {
+        let delegator = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+        let src_validator = "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string();
+        let dst_validator = "osmovaloper1ej2es5fjztqjcd4pwa0zyvaevtjd2y5w37wr9t".to_string();
+        let del_addr = decode_and_convert(&delegator).unwrap();
+        let src_addr = decode_and_convert(&src_validator).unwrap();
+        let dst_addr = decode_and_convert(&dst_validator).unwrap();
+
+        let red = CosmosRedelegation {
+            delegator_address: delegator.clone(),
+            validator_src_address: src_validator.clone(),
+            validator_dst_address: dst_validator.clone(),
+            entries: vec![CosmosRedelegationEntry {
+                creation_height: 100,
+                completion_time: Some(prost_types::Timestamp {
+                    seconds: 1_650_000_000,
+                    nanos: 0,
+                }),
+                initial_balance: "1000".to_string(),
+                shares_dst: "1000000000000000000000".to_string(),
+            }],
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_redelegation_key(&del_addr, &src_addr, &dst_addr).unwrap()),
+                value: Binary::from(red.encode_to_vec()),
+            },
+            // the delegator has no redelegations between the validators
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_redelegation_key(&del_addr, &dst_addr, &src_addr).unwrap()),
+                value: Binary::default(),
+            },
+        ];
+
+        let redelegations = Redelegations::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            redelegations,
+            Redelegations {
+                redelegations: vec![Redelegation {
+                    delegator_address: delegator,
+                    validator_src_address: src_validator.clone(),
+                    validator_dst_address: dst_validator.clone(),
+                    entries: vec![RedelegationEntry {
+                        creation_height: 100,
+                        completion_time: Some(Timestamp::from_seconds(1_650_000_000)),
+                        initial_balance: Uint128::new(1000),
+                        shares_dst: Decimal::from_str("1000").unwrap(),
+                    }],
+                }],
+                absent_keys: vec![KVKey {
+                    path: STAKING_STORE_KEY.to_string(),
+                    key: Binary(create_redelegation_key(&del_addr, &dst_addr, &src_addr).unwrap()),
+                }],
+            }
+        );
+
+        assert!(redelegations
+            .has_maturing_redelegation_to(&dst_validator, Timestamp::from_seconds(1_600_000_000)));
+        assert!(!redelegations
+            .has_maturing_redelegation_to(&dst_validator, Timestamp::from_seconds(1_650_000_000)));
+        assert!(!redelegations
+            .has_maturing_redelegation_to(&src_validator, Timestamp::from_seconds(1_600_000_000)));
+
+        // the redelegation is stored under the key of the opposite direction
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key = st_values[1].key.clone();
+        assert_eq!(
+            Redelegations::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "source validator {} is stored under the key of another address",
+                src_validator
+            ))
+        );
+
+        // redelegations of another delegator
+        let mut other_delegator = st_values.clone();
+        other_delegator[1].key =
+            Binary(create_redelegation_key(&src_addr, &dst_addr, &src_addr).unwrap());
+        assert_eq!(
+            Redelegations::reconstruct(&other_delegator).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "redelegations of different delegators".to_string()
+            )
+        );
+
+        // the same validators pair twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            Redelegations::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate redelegation".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values;
+        unexpected_key[1].key =
+            Binary(create_unbonding_delegation_key(&del_addr, &src_addr).unwrap());
+        assert_eq!(
+            Redelegations::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("unexpected redelegation key".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pending_rewards_reconstruct() {
+        let delegator = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+        let validator = "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string();
+        let del_addr = decode_and_convert(&delegator).unwrap();
+        let val_addr = decode_and_convert(&validator).unwrap();
+
+        let historical_rewards = |period: u64, ratio: &str| StorageValue {
+            storage_prefix: DISTRIBUTION_STORE_KEY.to_string(),
+            key: Binary(create_validator_historical_rewards_key(&val_addr, period).unwrap()),
+            value: Binary::from(
+                ValidatorHistoricalRewards {
+                    cumulative_reward_ratio: vec![DecCoin {
+                        denom: "uatom".to_string(),
+                        amount: ratio.to_string(),
+                    }],
+                    reference_count: 1,
+                }
+                .encode_to_vec(),
+            ),
+        };
+
+        let mut st_values = vec![
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_validator_key(&val_addr).unwrap()),
+                value: Binary::from(
+                    Validator {
+                        operator_address: validator.clone(),
+                        // the validator was slashed by 10%
+                        tokens: "900".to_string(),
+                        delegator_shares: "1000000000000000000000".to_string(),
+                        ..Validator::default()
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+            StorageValue {
+                storage_prefix: STAKING_STORE_KEY.to_string(),
+                key: Binary(create_delegation_key(&del_addr, &val_addr).unwrap()),
+                value: Binary::from(
+                    Delegation {
+                        delegator_address: delegator.clone(),
+                        validator_address: validator.clone(),
+                        shares: "100000000000000000000".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+            StorageValue {
+                storage_prefix: DISTRIBUTION_STORE_KEY.to_string(),
+                key: Binary(create_delegator_starting_info_key(&val_addr, &del_addr).unwrap()),
+                value: Binary::from(
+                    DelegatorStartingInfo {
+                        previous_period: 1,
+                        stake: "100000000000000000000".to_string(),
+                        height: 10,
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+            StorageValue {
+                storage_prefix: DISTRIBUTION_STORE_KEY.to_string(),
+                key: Binary(create_validator_current_rewards_key(&val_addr).unwrap()),
+                value: Binary::from(
+                    ValidatorCurrentRewards {
+                        rewards: vec![DecCoin {
+                            denom: "uatom".to_string(),
+                            amount: "45000000000000000000".to_string(),
+                        }],
+                        period: 3,
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+            StorageValue {
+                storage_prefix: DISTRIBUTION_STORE_KEY.to_string(),
+                key: Binary(create_validator_slash_event_key(&val_addr, 15, 2).unwrap()),
+                value: Binary::from(
+                    ValidatorSlashEvent {
+                        validator_period: 2,
+                        fraction: "100000000000000000".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+        ];
+
+        // historical rewards are not in the query yet
+        let pending_rewards = PendingRewards::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            pending_rewards,
+            PendingRewards {
+                rewards: vec![],
+                total: vec![],
+                missing_keys: vec![
+                    KVKey {
+                        path: DISTRIBUTION_STORE_KEY.to_string(),
+                        key: Binary(create_validator_historical_rewards_key(&val_addr, 1).unwrap()),
+                    },
+                    KVKey {
+                        path: DISTRIBUTION_STORE_KEY.to_string(),
+                        key: Binary(create_validator_historical_rewards_key(&val_addr, 2).unwrap()),
+                    },
+                ],
+            }
+        );
+
+        st_values.push(historical_rewards(1, "100000000000000000"));
+        st_values.push(historical_rewards(2, "300000000000000000"));
+
+        // (0.3 - 0.1) * 100 before the slash and (0.3 + 45 / 900 - 0.3) * 90 after the slash
+        let pending_rewards = PendingRewards::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            pending_rewards,
+            PendingRewards {
+                rewards: vec![DelegationRewards {
+                    validator_address: validator,
+                    rewards: vec![StdCoin::new(24, "uatom")],
+                }],
+                total: vec![StdCoin::new(24, "uatom")],
+                missing_keys: vec![],
+            }
+        );
+
+        // the slash event is not in the query, so the stake is greater than current stake
+        let mut without_slash = st_values.clone();
+        without_slash.remove(4);
+        assert_eq!(
+            PendingRewards::reconstruct(&without_slash).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "stake 100 of the delegation to {} is greater than current stake 90, \
+                 slash events of the validator are missing",
+                validator
+            ))
+        );
+
+        // the delegator has no delegation to the validator
+        let mut no_delegation = st_values.clone();
+        no_delegation[1].value = Binary::default();
+        no_delegation[2].value = Binary::default();
+        let pending_rewards = PendingRewards::reconstruct(&no_delegation).unwrap();
+        assert_eq!(pending_rewards.rewards, vec![]);
+        assert_eq!(pending_rewards.total, vec![]);
+
+        // the delegation exists without its starting info
+        let mut no_starting_info = st_values.clone();
+        no_starting_info[2].value = Binary::default();
+        assert_eq!(
+            PendingRewards::reconstruct(&no_starting_info).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "delegation doesn't match its starting info".to_string()
+            )
+        );
+
+        // the validator and its current rewards always exist
+        for (i, err) in [
+            (0, "validator is empty"),
+            (3, "validator current rewards are empty"),
+            (4, "validator slash event is empty"),
+        ] {
+            let mut empty_value = st_values.clone();
+            empty_value[i].value = Binary::default();
+            assert_eq!(
+                PendingRewards::reconstruct(&empty_value).unwrap_err(),
+                NeutronError::InvalidQueryResultFormat(err.to_string())
+            );
+        }
+
+        // keys which are not registered by the query are rejected
+        let mut unexpected_key = st_values.clone();
+        unexpected_key[0].storage_prefix = BANK_STORE_KEY.to_string();
+        assert_eq!(
+            PendingRewards::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "unexpected key {} in the bank store",
+                unexpected_key[0].key
+            ))
+        );
+        let mut empty_key = st_values;
+        empty_key[0].key = Binary::default();
+        assert_eq!(
+            PendingRewards::reconstruct(&empty_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("empty key in the staking store".to_string())
+        );
+    }
+
+    #[test]
+    fn test_gov_proposals_reconstruct() {
+        let proposal = Proposal {
+            proposal_id: 1,
+            content: Some(prost_types::Any {
+                type_url: "/cosmos.gov.v1beta1.TextProposal".to_string(),
+                value: vec![],
+            }),
+            status: 3,
+            final_tally_result: Some(CosmosTallyResult {
+                yes: "100".to_string(),
+                abstain: "10".to_string(),
+                no: "20".to_string(),
+                no_with_veto: "0".to_string(),
+            }),
+            submit_time: Some(prost_types::Timestamp {
+                seconds: 1_650_000_000,
+                nanos: 0,
+            }),
+            deposit_end_time: None,
+            total_deposit: vec![Coin {
+                denom: "uatom".to_string(),
+                amount: "512000000".to_string(),
+            }],
+            voting_start_time: None,
+            voting_end_time: Some(prost_types::Timestamp {
+                seconds: 1_651_000_000,
+                nanos: 0,
+            }),
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: GOV_STORE_KEY.to_string(),
+                key: Binary(create_gov_proposal_key(1)),
+                value: Binary::from(proposal.encode_to_vec()),
+            },
+            // proposal doesn't exist on remote chain
+            StorageValue {
+                storage_prefix: GOV_STORE_KEY.to_string(),
+                key: Binary(create_gov_proposal_key(2)),
+                value: Binary::default(),
+            },
+        ];
+
+        let proposals = GovProposals::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            proposals,
+            GovProposals {
+                proposals: vec![GovProposal {
+                    proposal_id: 1,
+                    proposal_type: Some("/cosmos.gov.v1beta1.TextProposal".to_string()),
+                    status: ProposalStatus::Passed,
+                    final_tally_result: Some(TallyResult {
+                        yes: Uint128::new(100),
+                        no: Uint128::new(20),
+                        abstain: Uint128::new(10),
+                        no_with_veto: Uint128::zero(),
+                    }),
+                    submit_time: Some(Timestamp::from_seconds(1_650_000_000)),
+                    deposit_end_time: None,
+                    total_deposit: vec![StdCoin::new(512000000, "uatom")],
+                    voting_start_time: None,
+                    voting_end_time: Some(Timestamp::from_seconds(1_651_000_000)),
+                }],
+                absent_keys: vec![KVKey {
+                    path: GOV_STORE_KEY.to_string(),
+                    key: Binary(create_gov_proposal_key(2)),
+                }],
+            }
+        );
+
+        // the proposal is stored under the key of another proposal
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key = Binary(create_gov_proposal_key(2));
+        assert_eq!(
+            GovProposals::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "proposal 1 is stored under the key of proposal 2".to_string()
+            )
+        );
+
+        // the same proposal twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            GovProposals::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate proposal 2".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values.clone();
+        unexpected_key[1].key = Binary(create_gov_proposal_votes_key(2));
+        assert_eq!(
+            GovProposals::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("unexpected proposal key".to_string())
+        );
+
+        // truncated proposal id
+        let mut short_key = st_values;
+        short_key[1].key.0.pop();
+        assert_eq!(
+            GovProposals::reconstruct(&short_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid proposal key".to_string())
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_gov_proposal_votes_reconstruct() {
+        let voter = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+        let legacy_voter_addr = vec![2u8; 20];
+        let legacy_voter = encode_and_convert("osmo", &legacy_voter_addr).unwrap();
+        let absent_voter_addr = vec![3u8; 20];
+        let voter_addr = decode_and_convert(&voter).unwrap();
+
+        let vote = Vote {
+            proposal_id: 1,
+            voter: voter.clone(),
+            option: 0,
+            options: vec![
+                CosmosWeightedVoteOption {
+                    option: 1,
+                    weight: "700000000000000000".to_string(),
+                },
+                CosmosWeightedVoteOption {
+                    option: 4,
+                    weight: "300000000000000000".to_string(),
+                },
+            ],
+        };
+        // vote stored before weighted voting was introduced
+        let legacy_vote = Vote {
+            proposal_id: 1,
+            voter: legacy_voter.clone(),
+            option: 3,
+            options: vec![],
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: GOV_STORE_KEY.to_string(),
+                key: Binary(create_gov_proposal_voter_votes_key(1, &voter_addr).unwrap()),
+                value: Binary::from(vote.encode_to_vec()),
+            },
+            StorageValue {
+                storage_prefix: GOV_STORE_KEY.to_string(),
+                key: Binary(create_gov_proposal_voter_votes_key(1, &legacy_voter_addr).unwrap()),
+                value: Binary::from(legacy_vote.encode_to_vec()),
+            },
+            // voter hasn't voted for the proposal
+            StorageValue {
+                storage_prefix: GOV_STORE_KEY.to_string(),
+                key: Binary(create_gov_proposal_voter_votes_key(1, &absent_voter_addr).unwrap()),
+                value: Binary::default(),
+            },
+        ];
+
+        let votes = GovProposalVotes::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            votes,
+            GovProposalVotes {
+                votes: vec![
+                    ProposalVote {
+                        proposal_id: 1,
+                        voter,
+                        options: vec![
+                            WeightedVoteOption {
+                                option: VoteOption::Yes,
+                                weight: Decimal::percent(70),
+                            },
+                            WeightedVoteOption {
+                                option: VoteOption::NoWithVeto,
+                                weight: Decimal::percent(30),
+                            },
+                        ],
+                    },
+                    ProposalVote {
+                        proposal_id: 1,
+                        voter: legacy_voter,
+                        options: vec![WeightedVoteOption {
+                            option: VoteOption::No,
+                            weight: Decimal::one(),
+                        }],
+                    },
+                ],
+                absent_keys: vec![KVKey {
+                    path: GOV_STORE_KEY.to_string(),
+                    key: Binary(
+                        create_gov_proposal_voter_votes_key(1, &absent_voter_addr).unwrap()
+                    ),
+                }],
+            }
+        );
+
+        // the vote is stored under the key of another voter
+        let mut wrong_voter = st_values.clone();
+        wrong_voter[0].key = st_values[2].key.clone();
+        assert_eq!(
+            GovProposalVotes::reconstruct(&wrong_voter[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "voter {} is stored under the key of another address",
+                voter
+            ))
+        );
+
+        // the vote is stored under the key of another proposal
+        let mut wrong_proposal = st_values.clone();
+        wrong_proposal[0].key =
+            Binary(create_gov_proposal_voter_votes_key(2, &voter_addr).unwrap());
+        assert_eq!(
+            GovProposalVotes::reconstruct(&wrong_proposal[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "vote for proposal 1 is stored under the key of proposal 2".to_string()
+            )
+        );
+
+        // votes for different proposals
+        let mut different_proposals = st_values.clone();
+        different_proposals[2].key =
+            Binary(create_gov_proposal_voter_votes_key(2, &absent_voter_addr).unwrap());
+        assert_eq!(
+            GovProposalVotes::reconstruct(&different_proposals).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("votes for different proposals".to_string())
+        );
+
+        // the same voter twice
+        let duplicate = vec![st_values[2].clone(), st_values[2].clone()];
+        assert_eq!(
+            GovProposalVotes::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate proposal vote".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values;
+        unexpected_key[2].key = Binary(create_gov_proposal_key(1));
+        assert_eq!(
+            GovProposalVotes::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("unexpected proposal vote key".to_string())
+        );
+    }
+
+    #[test]
+    fn test_account_info_reconstruct() {
+        let address = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+        let key = Binary(create_account_key(decode_and_convert(&address).unwrap()));
+
+        let base_account = BaseAccount {
+            address: address.clone(),
+            pub_key: Some(prost_types::Any {
+                type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+                value: vec![10, 2, 1, 2],
+            }),
+            account_number: 42,
+            sequence: 7,
+        };
+        let vesting_account = ContinuousVestingAccount {
+            base_vesting_account: Some(BaseVestingAccount {
+                base_account: Some(BaseAccount {
+                    pub_key: None,
+                    ..base_account.clone()
+                }),
+                original_vesting: vec![Coin {
+                    denom: "uosmo".to_string(),
+                    amount: "1000".to_string(),
+                }],
+                delegated_free: vec![],
+                delegated_vesting: vec![],
+                end_time: 1_660_000_000,
+            }),
+            start_time: 1_650_000_000,
+        };
+
+        let account_value = |type_url: &str, value: Vec<u8>| StorageValue {
+            storage_prefix: ACCOUNT_STORE_KEY.to_string(),
+            key: key.clone(),
+            value: Binary::from(
+                prost_types::Any {
+                    type_url: type_url.to_string(),
+                    value,
+                }
+                .encode_to_vec(),
+            ),
+        };
+
+        let account_info = AccountInfo::reconstruct(&[account_value(
+            "/cosmos.auth.v1beta1.BaseAccount",
+            base_account.encode_to_vec(),
+        )])
+        .unwrap();
+        assert_eq!(
+            account_info,
+            AccountInfo {
+                account: Some(Account {
+                    address: address.clone(),
+                    account_type: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+                    account_number: 42,
+                    sequence: 7,
+                    pub_key: Some(ProtobufAny::new(
+                        "/cosmos.crypto.secp256k1.PubKey".to_string(),
+                        Binary::from(vec![10, 2, 1, 2]),
+                    )),
+                    module_name: None,
+                    vesting: None,
+                })
+            }
+        );
+
+        let account_info = AccountInfo::reconstruct(&[account_value(
+            "/cosmos.vesting.v1beta1.ContinuousVestingAccount",
+            vesting_account.encode_to_vec(),
+        )])
+        .unwrap();
+        assert_eq!(
+            account_info,
+            AccountInfo {
+                account: Some(Account {
+                    address: address.clone(),
+                    account_type: "/cosmos.vesting.v1beta1.ContinuousVestingAccount".to_string(),
+                    account_number: 42,
+                    sequence: 7,
+                    pub_key: None,
+                    module_name: None,
+                    vesting: Some(VestingSchedule {
+                        kind: VestingKind::Continuous,
+                        original_vesting: vec![StdCoin::new(1000, "uosmo")],
+                        delegated_free: vec![],
+                        delegated_vesting: vec![],
+                        start_time: Some(Timestamp::from_seconds(1_650_000_000)),
+                        end_time: Timestamp::from_seconds(1_660_000_000),
+                        periods: vec![],
+                    }),
+                })
+            }
+        );
+
+        // account doesn't exist on remote chain
+        let account_info = AccountInfo::reconstruct(&[StorageValue {
+            storage_prefix: ACCOUNT_STORE_KEY.to_string(),
+            key: key.clone(),
+            value: Binary::default(),
+        }])
+        .unwrap();
+        assert!(!account_info.exists());
+
+        let err =
+            AccountInfo::reconstruct(&[account_value("/ethermint.types.v1.EthAccount", vec![])])
+                .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat(
+                "unsupported account type: /ethermint.types.v1.EthAccount".to_string()
+            )
+        );
+
+        // the account is stored under the key of another account
+        let mut wrong_key = account_value(
+            "/cosmos.auth.v1beta1.BaseAccount",
+            base_account.encode_to_vec(),
+        );
+        wrong_key.key = Binary(create_account_key(vec![1u8; 20]));
+        assert_eq!(
+            AccountInfo::reconstruct(&[wrong_key]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "account {} is stored under the key of another address",
+                address
+            ))
+        );
+
+        // the key has no address
+        let mut empty_address = account_value(
+            "/cosmos.auth.v1beta1.BaseAccount",
+            base_account.encode_to_vec(),
+        );
+        empty_address.key = Binary(vec![ACCOUNT_ADDRESS_PREFIX]);
+        assert_eq!(
+            AccountInfo::reconstruct(&[empty_address]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid account key".to_string())
+        );
+
+        // a value of another store
+        let mut wrong_store = account_value(
+            "/cosmos.auth.v1beta1.BaseAccount",
+            base_account.encode_to_vec(),
+        );
+        wrong_store.storage_prefix = BANK_STORE_KEY.to_string();
+        assert_eq!(
+            AccountInfo::reconstruct(&[wrong_store]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from acc store, got bank".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_validator_signing_info_reconstruct() {
+        let validator = StakingValidator {
+            operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            consensus_pubkey: Some(ProtobufAny::new(
+                ED25519_PUBKEY_TYPE_URL.to_string(),
+                Binary::from(
+                    PubKey {
+                        key: (0u8..32).collect(),
+                    }
+                    .encode_to_vec(),
+                ),
+            )),
+            jailed: false,
+            status: BondStatus::Bonded,
+            tokens: Uint128::new(1_000_000),
+            delegator_shares: Decimal::from_str("1000000").unwrap(),
+            description: None,
+            unbonding_height: 0,
+            unbonding_time: None,
+            commission: None,
+            min_self_delegation: Uint128::new(1),
+        };
+
+        let cons_addr = validator.consensus_address().unwrap();
+        let cons_address = encode_and_convert("cosmosvalcons", &cons_addr).unwrap();
+        assert_eq!(
+            cons_address,
+            "cosmosvalcons1vvxu62txcsekdygj23ythvjmfl6p9fyuyk54c2"
+        );
+
+        let absent_cons_addr = vec![1u8; 20];
+
+        let signing_info = CosmosValidatorSigningInfo {
+            address: cons_address.clone(),
+            start_height: 100,
+            index_offset: 2000,
+            jailed_until: Some(prost_types::Timestamp {
+                seconds: 0,
+                nanos: 0,
+            }),
+            tombstoned: false,
+            missed_blocks_counter: 15,
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: SLASHING_STORE_KEY.to_string(),
+                key: Binary(create_validator_signing_info_key(&cons_addr).unwrap()),
+                value: Binary::from(signing_info.encode_to_vec()),
+            },
+            // validator doesn't have signing info on remote chain
+            StorageValue {
+                storage_prefix: SLASHING_STORE_KEY.to_string(),
+                key: Binary(create_validator_signing_info_key(&absent_cons_addr).unwrap()),
+                value: Binary::default(),
+            },
+        ];
+
+        let signing_infos = ValidatorSigningInfo::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            signing_infos,
+            ValidatorSigningInfo {
+                signing_infos: vec![SigningInfo {
+                    address: cons_address,
+                    start_height: 100,
+                    index_offset: 2000,
+                    jailed_until: Some(Timestamp::from_seconds(0)),
+                    tombstoned: false,
+                    missed_blocks_counter: 15,
+                }],
+                absent_keys: vec![KVKey {
+                    path: SLASHING_STORE_KEY.to_string(),
+                    key: Binary(create_validator_signing_info_key(&absent_cons_addr).unwrap()),
+                }],
+            }
+        );
+
+        // the signing info is stored under the key of another validator
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key = st_values[1].key.clone();
+        assert_eq!(
+            ValidatorSigningInfo::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "validator consensus address cosmosvalcons1vvxu62txcsekdygj23ythvjmfl6p9fyuyk54c2 \
+                 is stored under the key of another address"
+                    .to_string()
+            )
+        );
+
+        // the same validator twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            ValidatorSigningInfo::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate signing info".to_string())
+        );
+
+        // a value of another store
+        let mut wrong_store = st_values;
+        wrong_store[1].storage_prefix = STAKING_STORE_KEY.to_string();
+        assert_eq!(
+            ValidatorSigningInfo::reconstruct(&wrong_store).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from slashing store, got staking".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_wasm_contract_state_reconstruct() {
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+        struct TokenInfo {
+            name: String,
+            symbol: String,
+            decimals: u8,
+            total_supply: Uint128,
+        }
+
+        let contract_address =
+            decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+
+        let balance_key = create_cw_storage_map_key("balance", &["osmo1holder"]).unwrap();
+        let mut expected_balance_key: Vec<u8> = vec![0, 7];
+        expected_balance_key.extend_from_slice(b"balance");
+        expected_balance_key.extend_from_slice(b"osmo1holder");
+        assert_eq!(balance_key, expected_balance_key);
+
+        let allowance_key =
+            create_cw_storage_map_key("allowance", &["osmo1owner", "osmo1spender"]).unwrap();
+        let mut expected_allowance_key: Vec<u8> = vec![0, 9];
+        expected_allowance_key.extend_from_slice(b"allowance");
+        expected_allowance_key.extend_from_slice(&[0, 10]);
+        expected_allowance_key.extend_from_slice(b"osmo1owner");
+        expected_allowance_key.extend_from_slice(b"osmo1spender");
+        assert_eq!(allowance_key, expected_allowance_key);
+
+        let store_key = create_wasm_contract_store_key(&contract_address, b"token_info");
+        assert_eq!(store_key[0], WASM_CONTRACT_STORE_PREFIX);
+        assert_eq!(
+            &store_key[1..contract_address.len() + 1],
+            contract_address.as_slice()
+        );
+        assert_eq!(&store_key[contract_address.len() + 1..], b"token_info");
+
+        let token_info = TokenInfo {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            total_supply: Uint128::new(1_000_000),
+        };
+
+        // Item value stored by cw-storage-plus
+        let state = WasmContractState::<TokenInfo>::reconstruct(&[StorageValue {
+            storage_prefix: WASM_STORE_KEY.to_string(),
+            key: Binary(store_key),
+            value: to_binary(&token_info).unwrap(),
+        }])
+        .unwrap();
+        assert_eq!(
+            state,
+            WasmContractState {
+                value: Some(token_info)
+            }
+        );
+
+        // there is no balance of the holder in the remote contract's storage
+        let state = WasmContractState::<Uint128>::reconstruct(&[StorageValue {
+            storage_prefix: WASM_STORE_KEY.to_string(),
+            key: Binary(create_wasm_contract_store_key(
+                &contract_address,
+                &balance_key,
+            )),
+            value: Binary::default(),
+        }])
+        .unwrap();
+        assert_eq!(state, WasmContractState { value: None });
+
+        // the stored value can't be decoded to the requested type
+        let err = WasmContractState::<Uint128>::reconstruct(&[StorageValue {
+            storage_prefix: WASM_STORE_KEY.to_string(),
+            key: Binary(create_wasm_contract_store_key(
+                &contract_address,
+                &balance_key,
+            )),
+            value: Binary::from(br#"{"amount":"100"}"#.to_vec()),
+        }]);
+        assert!(err.is_err());
+
+        // a key of another wasm store structure
+        let err = WasmContractState::<Uint128>::reconstruct(&[StorageValue {
+            storage_prefix: WASM_STORE_KEY.to_string(),
+            key: Binary(create_cw_storage_map_key("balance", &["osmo1holder"]).unwrap()),
+            value: Binary::default(),
+        }])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat(
+                "unexpected wasm contract store key".to_string()
+            )
+        );
+
+        // the key has no contract address and storage key
+        let err = WasmContractState::<Uint128>::reconstruct(&[StorageValue {
+            storage_prefix: WASM_STORE_KEY.to_string(),
+            key: Binary(vec![WASM_CONTRACT_STORE_PREFIX]),
+            value: Binary::default(),
+        }])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat("invalid wasm contract store key".to_string())
+        );
+
+        // a value of another store
+        let err = WasmContractState::<Uint128>::reconstruct(&[StorageValue {
+            storage_prefix: BANK_STORE_KEY.to_string(),
+            key: Binary(create_wasm_contract_store_key(
+                &contract_address,
+                &balance_key,
+            )),
+            value: Binary::default(),
+        }])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from wasm store, got bank".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_gamm_pool_reconstruct() {
+        let weight = Uint128::new(1_073_741_824_000_000);
+        let swap_fee = "2000000000000000".to_string(); // 0.002
+        let exit_fee = "0".to_string();
+
+        let balancer_pool = OsmosisBalancerPool {
+            address: "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t".to_string(),
+            id: 1,
+            pool_params: Some(OsmosisBalancerPoolParams {
+                swap_fee: swap_fee.clone(),
+                exit_fee: exit_fee.clone(),
+                ..Default::default()
+            }),
+            total_shares: Some(OsmosisCoin {
+                denom: "gamm/pool/1".to_string(),
+                amount: "100000000000000000000".to_string(),
+            }),
+            pool_assets: vec![
+                OsmosisBalancerPoolAsset {
+                    token: Some(OsmosisCoin {
+                        denom: "uatom".to_string(),
+                        amount: "100000000".to_string(),
+                    }),
+                    weight: (weight * Uint128::new(2)).to_string(),
+                },
+                OsmosisBalancerPoolAsset {
+                    token: Some(OsmosisCoin {
+                        denom: "uosmo".to_string(),
+                        amount: "1000000000".to_string(),
+                    }),
+                    weight: weight.to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let pool_value = |type_url: &str, value: Vec<u8>| StorageValue {
+            storage_prefix: GAMM_STORE_KEY.to_string(),
+            key: Binary(create_gamm_pool_key(1)),
+            value: Binary::from(
+                prost_types::Any {
+                    type_url: type_url.to_string(),
+                    value,
+                }
+                .encode_to_vec(),
+            ),
+        };
+
+        let pool = GammPool::reconstruct(&[pool_value(
+            GAMM_BALANCER_POOL_TYPE_URL,
+            balancer_pool.encode_to_vec(),
+        )])
+        .unwrap();
+        assert_eq!(
+            pool,
+            GammPool {
+                id: 1,
+                address: "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t"
+                    .to_string(),
+                pool_type: GammPoolType::Balancer,
+                assets: vec![
+                    GammPoolAsset {
+                        token: StdCoin::new(100_000_000, "uatom"),
+                        weight: Some(weight * Uint128::new(2)),
+                        scaling_factor: None,
+                    },
+                    GammPoolAsset {
+                        token: StdCoin::new(1_000_000_000, "uosmo"),
+                        weight: Some(weight),
+                        scaling_factor: None,
+                    },
+                ],
+                swap_fee: Decimal::from_str("0.002").unwrap(),
+                exit_fee: Decimal::zero(),
+                total_shares: StdCoin::new(100_000_000_000_000_000_000, "gamm/pool/1"),
             }
-        }
+        );
+
+        // (1000 OSMO / W) / (100 ATOM / 2W)
+        assert_eq!(
+            pool.spot_price("uatom", "uosmo").unwrap(),
+            Decimal::from_str("20").unwrap()
+        );
+        // the weights ratio is 0.5, so the result is approximated
+        assert_eq!(
+            pool.expected_out(&StdCoin::new(10_000_000, "uosmo"), "uatom")
+                .unwrap(),
+            Uint128::new(495_295)
+        );
+        // the weights ratio is 2, so the result is calculated exactly
+        assert_eq!(
+            pool.expected_out(&StdCoin::new(1_000_000, "uatom"), "uosmo")
+                .unwrap(),
+            Uint128::new(19_665_125)
+        );
+        assert_eq!(
+            pool.min_out_amount(
+                &StdCoin::new(10_000_000, "uosmo"),
+                "uatom",
+                Decimal::percent(1)
+            )
+            .unwrap(),
+            Uint128::new(490_342)
+        );
+        assert!(pool
+            .expected_out(&StdCoin::new(1_000_000, "uatom"), "uusdc")
+            .is_err());
+
+        let stableswap_pool = OsmosisStableswapPool {
+            address: "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t".to_string(),
+            id: 1,
+            pool_params: Some(OsmosisStableswapPoolParams { swap_fee, exit_fee }),
+            total_shares: Some(OsmosisCoin {
+                denom: "gamm/pool/1".to_string(),
+                amount: "100000000000000000000".to_string(),
+            }),
+            pool_liquidity: vec![
+                OsmosisCoin {
+                    denom: "udai".to_string(),
+                    amount: "1000000000000000000000000".to_string(),
+                },
+                OsmosisCoin {
+                    denom: "uusdc".to_string(),
+                    amount: "1000000000000".to_string(),
+                },
+            ],
+            scaling_factor: vec![1_000_000_000_000, 1],
+            ..Default::default()
+        };
+
+        let pool = GammPool::reconstruct(&[pool_value(
+            GAMM_STABLESWAP_POOL_TYPE_URL,
+            stableswap_pool.encode_to_vec(),
+        )])
+        .unwrap();
+        assert_eq!(pool.pool_type, GammPoolType::Stableswap);
+        assert_eq!(
+            pool.assets,
+            vec![
+                GammPoolAsset {
+                    token: StdCoin::new(1_000_000_000_000_000_000_000_000, "udai"),
+                    weight: None,
+                    scaling_factor: Some(1_000_000_000_000),
+                },
+                GammPoolAsset {
+                    token: StdCoin::new(1_000_000_000_000, "uusdc"),
+                    weight: None,
+                    scaling_factor: Some(1),
+                },
+            ]
+        );
+
+        // the reserves are balanced after scaling, so one unit of usdc costs 10^12 units of dai
+        assert_eq!(
+            pool.spot_price("uusdc", "udai").unwrap(),
+            Decimal::from_str("1000000000000").unwrap()
+        );
+        assert_eq!(
+            pool.expected_out(&StdCoin::new(1_000_000_000, "uusdc"), "udai")
+                .unwrap(),
+            Uint128::new(997_999_999_503_988_016_485)
+        );
+        assert_eq!(
+            pool.expected_out(
+                &StdCoin::new(1_000_000_000_000_000_000_000, "udai"),
+                "uusdc"
+            )
+            .unwrap(),
+            Uint128::new(997_999_999)
+        );
+
+        // pool doesn't exist on remote chain
+        assert_eq!(
+            GammPool::reconstruct(&[StorageValue {
+                storage_prefix: GAMM_STORE_KEY.to_string(),
+                key: Binary(create_gamm_pool_key(2)),
+                value: Binary::default(),
+            }])
+            .unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "pool 2 doesn't exist on remote chain".to_string()
+            )
+        );
+
+        // pool is stored under the key of another pool
+        let mut wrong_key_value = pool_value(
+            GAMM_STABLESWAP_POOL_TYPE_URL,
+            stableswap_pool.encode_to_vec(),
+        );
+        wrong_key_value.key = Binary(create_gamm_pool_key(2));
+        assert_eq!(
+            GammPool::reconstruct(&[wrong_key_value.clone()]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "pool 1 is stored under the key of pool 2".to_string()
+            )
+        );
+
+        // trailing bytes in the key
+        wrong_key_value.key = Binary([create_gamm_pool_key(1), vec![0]].concat());
+        assert_eq!(
+            GammPool::reconstruct(&[wrong_key_value.clone()]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid pool key".to_string())
+        );
+
+        // value from another store
+        wrong_key_value.key = Binary(create_gamm_pool_key(1));
+        wrong_key_value.storage_prefix = BANK_STORE_KEY.to_string();
+        assert!(GammPool::reconstruct(&[wrong_key_value]).is_err());
     }
 
     #[test]
-    fn test_delegations_reconstruct() {
-        struct TestCase {
-            stake_denom: String,
-            delegations: Vec<Delegation>,
-            validators: Vec<Validator>,
-            expected_result: NeutronResult<Delegations>,
-        }
-        let test_cases: Vec<TestCase> = vec![
-            TestCase {
-                stake_denom: "stake".to_string(),
-                delegations: vec![Delegation {
-                    delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
-                    validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                        .to_string(),
-                    shares: "1000000000000000000".to_string(),
+    fn test_denom_traces_reconstruct() {
+        let atom_denom = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+        assert_eq!(get_ibc_denom("transfer/channel-0", "uatom"), atom_denom);
+        assert_eq!(get_ibc_denom("", "uosmo"), "uosmo");
+
+        let atom_hash = decode_ibc_denom_hash(atom_denom).unwrap();
+        assert_eq!(atom_hash.len(), 32);
+        assert_eq!(
+            decode_ibc_denom_hash(
+                "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+            )
+            .unwrap(),
+            atom_hash
+        );
+        assert!(decode_ibc_denom_hash("ibc/27394FB0").is_err());
+        assert!(decode_ibc_denom_hash(
+            "ibc/Z7394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        )
+        .is_err());
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: TRANSFER_STORE_KEY.to_string(),
+                key: Binary(create_denom_trace_key(&atom_hash)),
+                value: Binary::from(
+                    IbcDenomTrace {
+                        path: "transfer/channel-0".to_string(),
+                        base_denom: "uatom".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
+            },
+            // denom trace doesn't exist on remote chain
+            StorageValue {
+                storage_prefix: TRANSFER_STORE_KEY.to_string(),
+                key: Binary(create_denom_trace_key(&[0u8; 32])),
+                value: Binary::default(),
+            },
+        ];
+
+        let traces = DenomTraces::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            traces,
+            DenomTraces {
+                traces: vec![DenomTrace {
+                    path: "transfer/channel-0".to_string(),
+                    base_denom: "uatom".to_string(),
                 }],
-                validators: vec![Validator {
-                    operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                        .to_string(),
-                    consensus_pubkey: None,
-                    jailed: false,
-                    status: 0,
-                    tokens: "1000000000000000000".to_string(),
-                    delegator_shares: "1000000000000000000".to_string(),
-                    description: None,
-                    unbonding_height: 0,
-                    unbonding_time: None,
-                    commission: None,
-                    min_self_delegation: "".to_string(),
+                absent_keys: vec![KVKey {
+                    path: TRANSFER_STORE_KEY.to_string(),
+                    key: Binary(create_denom_trace_key(&[0u8; 32])),
                 }],
-                expected_result: Ok(Delegations {
-                    delegations: vec![StdDelegation {
-                        delegator: Addr::unchecked("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"),
-                        validator: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
-                        amount: StdCoin::new(1000000000000000000u128, "stake"),
-                    }],
-                }),
+            }
+        );
+        assert_eq!(
+            traces.trace(atom_denom).map(|t| t.base_denom.as_str()),
+            Some("uatom")
+        );
+        assert_eq!(traces.trace("ibc/unknown"), None);
+
+        // the trace is stored under the key of another hash
+        let mut wrong_key = st_values.clone();
+        wrong_key[0].key = st_values[1].key.clone();
+        assert_eq!(
+            DenomTraces::reconstruct(&wrong_key[..1]).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "denom trace transfer/channel-0/uatom is stored under the key of another hash"
+                    .to_string()
+            )
+        );
+
+        // the same hash twice
+        let duplicate = vec![st_values[1].clone(), st_values[1].clone()];
+        assert_eq!(
+            DenomTraces::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("duplicate denom trace".to_string())
+        );
+
+        // truncated hash
+        let mut short_key = st_values.clone();
+        short_key[1].key.0.pop();
+        assert_eq!(
+            DenomTraces::reconstruct(&short_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid denom trace key".to_string())
+        );
+
+        // a value of another store
+        let mut wrong_store = st_values;
+        wrong_store[1].storage_prefix = BANK_STORE_KEY.to_string();
+        assert_eq!(
+            DenomTraces::reconstruct(&wrong_store).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from transfer store, got bank".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_authz_grants_and_fee_allowance_reconstruct() {
+        let granter = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs";
+        let grantee = "osmo1mw0ac6rwlp5r8wapwk3zs6g29h8fcscxqakdzw9emkne6c8wjp9q0t3v8t";
+        let granter_addr = decode_and_convert(granter).unwrap();
+        let grantee_addr = decode_and_convert(grantee).unwrap();
+
+        let any = |type_url: &str, value: Vec<u8>| prost_types::Any {
+            type_url: type_url.to_string(),
+            value,
+        };
+        let grant_value = |authorization: prost_types::Any, expiration: Option<i64>| {
+            Binary::from(
+                CosmosAuthzGrant {
+                    authorization: Some(authorization),
+                    expiration: expiration
+                        .map(|seconds| prost_types::Timestamp { seconds, nanos: 0 }),
+                }
+                .encode_to_vec(),
+            )
+        };
+        let grant_key = |msg_type_url: &str| {
+            Binary(create_authz_grant_key(&granter_addr, &grantee_addr, msg_type_url).unwrap())
+        };
+
+        let st_values = vec![
+            StorageValue {
+                storage_prefix: AUTHZ_STORE_KEY.to_string(),
+                key: grant_key("/cosmos.gov.v1beta1.MsgVote"),
+                value: grant_value(
+                    any(
+                        "/cosmos.authz.v1beta1.GenericAuthorization",
+                        CosmosGenericAuthorization {
+                            msg: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+                        }
+                        .encode_to_vec(),
+                    ),
+                    Some(1_650_000_000),
+                ),
             },
-            TestCase {
-                stake_denom: "stake".to_string(),
-                delegations: vec![
-                    Delegation {
-                        delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"
-                            .to_string(),
-                        validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                            .to_string(),
-                        shares: "1000000000000000000".to_string(),
-                    },
-                    Delegation {
-                        delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs"
-                            .to_string(),
-                        validator_address: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
-                            .to_string(),
-                        shares: "1000000000000000000".to_string(),
-                    },
-                ],
-                validators: vec![
-                    Validator {
-                        operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                            .to_string(),
-                        consensus_pubkey: None,
-                        jailed: false,
-                        status: 0,
-                        tokens: "1000000000000000000".to_string(),
-                        delegator_shares: "1000000000000000000".to_string(),
-                        description: None,
-                        unbonding_height: 0,
-                        unbonding_time: None,
-                        commission: None,
-                        min_self_delegation: "".to_string(),
+            StorageValue {
+                storage_prefix: AUTHZ_STORE_KEY.to_string(),
+                key: grant_key("/cosmos.staking.v1beta1.MsgDelegate"),
+                value: grant_value(
+                    any(
+                        "/cosmos.staking.v1beta1.StakeAuthorization",
+                        CosmosStakeAuthorization {
+                            max_tokens: Some(Coin {
+                                denom: "uosmo".to_string(),
+                                amount: "1000000".to_string(),
+                            }),
+                            allow_list: Some(CosmosStakeAuthorizationValidators {
+                                address: vec!["osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
+                                    .to_string()],
+                            }),
+                            deny_list: None,
+                            authorization_type: 1,
+                        }
+                        .encode_to_vec(),
+                    ),
+                    None,
+                ),
+            },
+            // there is no grant for MsgSend on remote chain
+            StorageValue {
+                storage_prefix: AUTHZ_STORE_KEY.to_string(),
+                key: grant_key("/cosmos.bank.v1beta1.MsgSend"),
+                value: Binary::default(),
+            },
+        ];
+
+        let grants = AuthzGrants::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            grants,
+            AuthzGrants {
+                grants: vec![
+                    AuthzGrant {
+                        msg_type_url: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+                        authorization: Authorization::Generic {
+                            msg: "/cosmos.gov.v1beta1.MsgVote".to_string()
+                        },
+                        expiration: Some(Timestamp::from_seconds(1_650_000_000)),
                     },
-                    Validator {
-                        operator_address: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
-                            .to_string(),
-                        consensus_pubkey: None,
-                        jailed: false,
-                        status: 0,
-                        tokens: "1000000000000000000".to_string(),
-                        delegator_shares: "1000000000000000000".to_string(),
-                        description: None,
-                        unbonding_height: 0,
-                        unbonding_time: None,
-                        commission: None,
-                        min_self_delegation: "".to_string(),
+                    AuthzGrant {
+                        msg_type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+                        authorization: Authorization::Stake {
+                            max_tokens: Some(StdCoin::new(1_000_000, "uosmo")),
+                            authorization_type: StakeAuthorizationType::Delegate,
+                            allow_list: vec![
+                                "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string()
+                            ],
+                            deny_list: vec![],
+                        },
+                        expiration: None,
                     },
                 ],
-                expected_result: Ok(Delegations {
-                    delegations: vec![
-                        StdDelegation {
-                            delegator: Addr::unchecked(
-                                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs",
-                            ),
-                            validator: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                                .to_string(),
-                            amount: StdCoin::new(1000000000000000000u128, "stake"),
-                        },
-                        StdDelegation {
-                            delegator: Addr::unchecked(
-                                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs",
-                            ),
-                            validator: "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we"
-                                .to_string(),
-                            amount: StdCoin::new(1000000000000000000u128, "stake"),
-                        },
-                    ],
+                absent_keys: vec![KVKey {
+                    path: AUTHZ_STORE_KEY.to_string(),
+                    key: grant_key("/cosmos.bank.v1beta1.MsgSend"),
+                }],
+            }
+        );
+
+        // grants of another grantee
+        let mut other_grantee = st_values.clone();
+        other_grantee[2].key = Binary(
+            create_authz_grant_key(&granter_addr, &granter_addr, "/cosmos.bank.v1beta1.MsgSend")
+                .unwrap(),
+        );
+        assert_eq!(
+            AuthzGrants::reconstruct(&other_grantee).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "authz grants of different granters or grantees".to_string()
+            )
+        );
+
+        // the same message type twice
+        let duplicate = vec![st_values[2].clone(), st_values[2].clone()];
+        assert_eq!(
+            AuthzGrants::reconstruct(&duplicate).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(
+                "duplicate authz grant for /cosmos.bank.v1beta1.MsgSend".to_string()
+            )
+        );
+
+        // the key has no message type
+        let mut no_msg_type = st_values.clone();
+        no_msg_type[2].key = grant_key("");
+        assert_eq!(
+            AuthzGrants::reconstruct(&no_msg_type).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("invalid authz grant key".to_string())
+        );
+
+        // a key of another structure
+        let mut unexpected_key = st_values.clone();
+        unexpected_key[2].key =
+            Binary(create_fee_allowance_key(&granter_addr, &grantee_addr).unwrap());
+        assert_eq!(
+            AuthzGrants::reconstruct(&unexpected_key).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat("unexpected authz grant key".to_string())
+        );
+
+        let now = Timestamp::from_seconds(1_660_000_000);
+        assert!(!grants.is_granted("/cosmos.gov.v1beta1.MsgVote", now));
+        assert!(grants.is_granted(
+            "/cosmos.gov.v1beta1.MsgVote",
+            Timestamp::from_seconds(1_640_000_000)
+        ));
+        assert!(grants.is_granted("/cosmos.staking.v1beta1.MsgDelegate", now));
+        assert!(!grants.is_granted("/cosmos.bank.v1beta1.MsgSend", now));
+
+        let periodic_allowance = CosmosPeriodicAllowance {
+            basic: Some(CosmosBasicAllowance {
+                spend_limit: vec![Coin {
+                    denom: "uosmo".to_string(),
+                    amount: "1000000".to_string(),
+                }],
+                expiration: Some(prost_types::Timestamp {
+                    seconds: 1_670_000_000,
+                    nanos: 0,
                 }),
+            }),
+            period: Some(prost_types::Duration {
+                seconds: 86_400,
+                nanos: 0,
+            }),
+            period_spend_limit: vec![Coin {
+                denom: "uosmo".to_string(),
+                amount: "10000".to_string(),
+            }],
+            period_can_spend: vec![Coin {
+                denom: "uosmo".to_string(),
+                amount: "2500".to_string(),
+            }],
+            period_reset: Some(prost_types::Timestamp {
+                seconds: 1_660_050_000,
+                nanos: 0,
+            }),
+        };
+        let fee_grant = CosmosFeeGrant {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+            allowance: Some(any(
+                "/cosmos.feegrant.v1beta1.AllowedMsgAllowance",
+                CosmosAllowedMsgAllowance {
+                    allowance: Some(any(
+                        "/cosmos.feegrant.v1beta1.PeriodicAllowance",
+                        periodic_allowance.encode_to_vec(),
+                    )),
+                    allowed_messages: vec!["/cosmos.authz.v1beta1.MsgExec".to_string()],
+                }
+                .encode_to_vec(),
+            )),
+        };
+
+        let allowance_key = Binary(create_fee_allowance_key(&granter_addr, &grantee_addr).unwrap());
+        let allowance = FeeGrantAllowance::reconstruct(&[StorageValue {
+            storage_prefix: FEEGRANT_STORE_KEY.to_string(),
+            key: allowance_key.clone(),
+            value: Binary::from(fee_grant.encode_to_vec()),
+        }])
+        .unwrap();
+        assert_eq!(
+            allowance,
+            FeeGrantAllowance {
+                allowance: Some(FeeAllowance {
+                    granter: granter.to_string(),
+                    grantee: grantee.to_string(),
+                    spend_limit: vec![StdCoin::new(1_000_000, "uosmo")],
+                    expiration: Some(Timestamp::from_seconds(1_670_000_000)),
+                    periodic: Some(PeriodicFeeAllowance {
+                        period_seconds: 86_400,
+                        period_spend_limit: vec![StdCoin::new(10_000, "uosmo")],
+                        period_can_spend: vec![StdCoin::new(2_500, "uosmo")],
+                        period_reset: Some(Timestamp::from_seconds(1_660_050_000)),
+                    }),
+                    allowed_messages: Some(vec!["/cosmos.authz.v1beta1.MsgExec".to_string()]),
+                })
+            }
+        );
+        assert!(allowance.is_valid(now));
+        assert!(!allowance.is_valid(Timestamp::from_seconds(1_670_000_000)));
+
+        // fee allowance doesn't exist on remote chain
+        let allowance = FeeGrantAllowance::reconstruct(&[StorageValue {
+            storage_prefix: FEEGRANT_STORE_KEY.to_string(),
+            key: allowance_key,
+            value: Binary::default(),
+        }])
+        .unwrap();
+        assert_eq!(allowance, FeeGrantAllowance { allowance: None });
+        assert!(!allowance.is_valid(now));
+
+        // the allowance is stored under the key of the opposite direction
+        let err = FeeGrantAllowance::reconstruct(&[StorageValue {
+            storage_prefix: FEEGRANT_STORE_KEY.to_string(),
+            key: Binary(create_fee_allowance_key(&grantee_addr, &granter_addr).unwrap()),
+            value: Binary::from(fee_grant.encode_to_vec()),
+        }])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat(format!(
+                "granter {} is stored under the key of another address",
+                granter
+            ))
+        );
+
+        // a value of another store
+        let err = FeeGrantAllowance::reconstruct(&[StorageValue {
+            storage_prefix: AUTHZ_STORE_KEY.to_string(),
+            key: Binary(create_fee_allowance_key(&granter_addr, &grantee_addr).unwrap()),
+            value: Binary::default(),
+        }])
+        .unwrap_err();
+        assert_eq!(
+            err,
+            NeutronError::InvalidQueryResultFormat(
+                "expected storage value from feegrant store, got authz".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_derive_kv_reconstruct() {
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+        struct Config {
+            owner: String,
+        }
+
+        #[derive(KVReconstruct, Debug, PartialEq)]
+        #[kv(keys(addr: Vec<u8>, denom: String))]
+        struct AccountState {
+            #[kv(path = "bank", key = create_account_denom_balance_key(addr, denom)?)]
+            balance: Option<Coin>,
+            #[kv(path = "wasm", json, key = create_wasm_contract_store_key(addr, b"config"))]
+            config: Config,
+            #[kv(path = "acc", raw, key = create_account_key(addr))]
+            account: Binary,
+        }
+
+        let addr = decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+        let state_keys = AccountStateKeys {
+            addr: addr.clone(),
+            denom: "uosmo".to_string(),
+        };
+        let keys = state_keys.kv_keys().unwrap();
+        assert_eq!(
+            keys,
+            vec![
+                KVKey {
+                    path: BANK_STORE_KEY.to_string(),
+                    key: Binary(create_account_denom_balance_key(&addr, "uosmo").unwrap()),
+                },
+                KVKey {
+                    path: WASM_STORE_KEY.to_string(),
+                    key: Binary(create_wasm_contract_store_key(&addr, b"config")),
+                },
+                KVKey {
+                    path: ACCOUNT_STORE_KEY.to_string(),
+                    key: Binary(create_account_key(&addr)),
+                },
+            ]
+        );
+
+        let config = Config {
+            owner: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        };
+        let mut st_values: Vec<StorageValue> = keys
+            .into_iter()
+            .map(|key| StorageValue {
+                storage_prefix: key.path,
+                key: key.key,
+                value: Binary::default(),
+            })
+            .collect();
+        st_values[1].value = to_binary(&config).unwrap();
+        st_values[2].value = Binary::from(vec![1, 2, 3]);
+
+        // there is no balance of the denom
+        let state = AccountState::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            state,
+            AccountState {
+                balance: None,
+                config: config.clone(),
+                account: Binary::from(vec![1, 2, 3]),
+            }
+        );
+
+        let balance = Coin {
+            denom: "uosmo".to_string(),
+            amount: "100".to_string(),
+        };
+        st_values[0].value = Binary::from(balance.encode_to_vec());
+        let state = AccountState::reconstruct(&st_values).unwrap();
+        assert_eq!(state.balance, Some(balance.clone()));
+
+        // the keys reconstruct checks the values are read by the same keys
+        let state = state_keys.reconstruct(&st_values).unwrap();
+        assert_eq!(state.balance, Some(balance));
+
+        // the value is read from the right store by the wrong key
+        let other_keys = AccountStateKeys {
+            addr: addr.clone(),
+            denom: "uatom".to_string(),
+        };
+        assert_eq!(
+            other_keys.reconstruct(&st_values).unwrap_err(),
+            NeutronError::InvalidQueryResultFormat(format!(
+                "expected key {} in the bank store, got key {} in the bank store",
+                Binary(create_account_denom_balance_key(&addr, "uatom").unwrap()),
+                Binary(create_account_denom_balance_key(&addr, "uosmo").unwrap()),
+            ))
+        );
+        assert_eq!(
+            other_keys.into_kv_keys().unwrap()[0].key,
+            Binary(create_account_denom_balance_key(&addr, "uatom").unwrap())
+        );
+
+        // values are read from wrong stores
+        st_values.swap(0, 1);
+        assert!(AccountState::reconstruct(&st_values).is_err());
+        assert!(state_keys.reconstruct(&st_values).is_err());
+
+        // not enough values
+        assert!(AccountState::reconstruct(&st_values[..2]).is_err());
+        assert!(state_keys.reconstruct(&st_values[..2]).is_err());
+    }
+
+    #[test]
+    fn test_composed_kv_reconstruct() {
+        let addr = decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+        let contract_key = create_wasm_contract_store_key(&addr, b"total");
+        let balance_key = create_account_denom_balance_key(&addr, "uosmo").unwrap();
+
+        let mut st_values: Vec<StorageValue> = vec![
+            StorageValue {
+                storage_prefix: WASM_STORE_KEY.to_string(),
+                key: Binary(contract_key.clone()),
+                value: to_binary(&Uint128::new(100)).unwrap(),
             },
-            TestCase {
-                stake_denom: "stake".to_string(),
-                delegations: vec![],
-                validators: vec![],
-                expected_result: Ok(Delegations {
-                    delegations: vec![],
-                }),
+            StorageValue {
+                storage_prefix: WASM_STORE_KEY.to_string(),
+                key: Binary(contract_key),
+                value: Binary::default(),
             },
-            TestCase {
-                stake_denom: Default::default(),
-                delegations: vec![],
-                validators: vec![],
-                expected_result: Err(NeutronError::InvalidQueryResultFormat(
-                    "denom is empty".into(),
-                )),
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(balance_key.clone()),
+                value: Binary::from(
+                    Coin {
+                        denom: "uosmo".to_string(),
+                        amount: "200".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
             },
-            TestCase {
-                stake_denom: "stake".to_string(),
-                delegations: vec![Delegation {
-                    delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
-                    validator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3"
-                        .to_string(),
-                    shares: "1000000000000000000".to_string(),
-                }],
-                validators: vec![],
-                expected_result: Err(NeutronError::InvalidQueryResultFormat(
-                    "validator is empty".into(),
-                )),
+            StorageValue {
+                storage_prefix: BANK_STORE_KEY.to_string(),
+                key: Binary(balance_key),
+                value: Binary::from(
+                    Coin {
+                        denom: "uatom".to_string(),
+                        amount: "300".to_string(),
+                    }
+                    .encode_to_vec(),
+                ),
             },
         ];
 
-        for ts in &test_cases {
-            // prepare storage values
-            let mut st_values: Vec<StorageValue> = vec![StorageValue {
-                storage_prefix: STAKING_STORE_KEY.to_string(),
-                key: Binary(create_params_store_key(STAKING_STORE_KEY, KEY_BOND_DENOM)),
-                value: {
-                    if ts.stake_denom.is_empty() {
-                        return Default::default();
-                    }
-                    to_binary(&ts.stake_denom).unwrap()
-                },
-            }];
+        // single value reconstructs consume one value each, the last one consumes the rest
+        let (total, missing, balances) = <(
+            WasmContractState<Uint128>,
+            Option<WasmContractState<Uint128>>,
+            Balances,
+        )>::reconstruct(&st_values)
+        .unwrap();
+        assert_eq!(total.value, Some(Uint128::new(100)));
+        assert_eq!(missing, None);
+        assert_eq!(
+            balances.coins,
+            vec![StdCoin::new(200, "uosmo"), StdCoin::new(300, "uatom"),]
+        );
+        assert_eq!(<(WasmContractState<Uint128>, Balances)>::kv_count(), None);
+        assert_eq!(<(RawKv, Option<RawKv>)>::kv_count(), Some(2));
 
-            for (i, d) in ts.delegations.iter().enumerate() {
-                let delegator_addr = decode_and_convert(&d.delegator_address).unwrap();
-                let val_addr = decode_and_convert(&d.validator_address).unwrap();
+        let raw = Vec::<RawKv>::reconstruct(&st_values).unwrap();
+        assert_eq!(
+            raw,
+            st_values.iter().cloned().map(RawKv).collect::<Vec<RawKv>>()
+        );
 
-                st_values.push(StorageValue {
-                    storage_prefix: STAKING_STORE_KEY.to_string(),
-                    key: Binary(create_delegation_key(&delegator_addr, &val_addr).unwrap()),
-                    value: Binary::from(d.encode_to_vec()),
-                });
+        let pairs = Vec::<(RawKv, RawKv)>::reconstruct(&st_values).unwrap();
+        assert_eq!(pairs.len(), 2);
+        assert_eq!(pairs[1].0, RawKv(st_values[2].clone()));
 
-                if let Some(v) = ts.validators.get(i) {
-                    st_values.push(StorageValue {
-                        storage_prefix: STAKING_STORE_KEY.to_string(),
-                        key: Binary(create_validator_key(&val_addr).unwrap()),
-                        value: Binary::from(v.encode_to_vec()),
-                    });
-                }
+        // the number of values doesn't match the tuple
+        assert!(<(RawKv, RawKv, RawKv)>::reconstruct(&st_values).is_err());
+        assert!(Vec::<(RawKv, RawKv)>::reconstruct(&st_values[..3]).is_err());
+        assert!(<(RawKv, RawKv, RawKv, RawKv, RawKv)>::reconstruct(&st_values).is_err());
+
+        // values are consumed in order
+        st_values.swap(0, 1);
+        let (total, missing, _) = <(
+            WasmContractState<Uint128>,
+            Option<WasmContractState<Uint128>>,
+            Balances,
+        )>::reconstruct(&st_values)
+        .unwrap();
+        assert_eq!(total.value, None);
+        assert_eq!(missing.unwrap().value, Some(Uint128::new(100)));
+    }
+
+    #[test]
+    fn test_tx_filter() {
+        let filter = TxFilter::new()
+            .eq(
+                RECIPIENT_FIELD,
+                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs",
+            )
+            .unwrap()
+            .eq("message.action", "/cosmos.staking.v1beta1.MsgDelegate")
+            .unwrap()
+            .eq(
+                "delegate.validator",
+                "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3",
+            )
+            .unwrap()
+            .eq(
+                "ibc_transfer.receiver",
+                "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+            )
+            .unwrap()
+            .gte(HEIGHT_FIELD, 100u64)
+            .unwrap()
+            .lt(HEIGHT_FIELD, u64::MAX)
+            .unwrap()
+            .gt("transfer.amount", 1u64)
+            .unwrap()
+            .lte("transfer.amount", 1000u64)
+            .unwrap();
+        assert_eq!(filter.len(), 8);
+        assert_eq!(
+            filter.items()[4],
+            TransactionFilterItem {
+                field: HEIGHT_FIELD.to_string(),
+                op: TransactionFilterOp::Gte,
+                value: TransactionFilterValue::Int(100),
             }
+        );
 
-            // test reconstruction
-            let delegations = Delegations::reconstruct(&st_values);
+        let json = filter.to_json().unwrap();
+        assert!(json.contains(r#"{"field":"tx.height","op":"Gte","value":100}"#));
+
+        // the filter round-trips with both JSON serializers
+        let decoded: TxFilter = schemars::_serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, filter);
+        let decoded: TxFilter =
+            serde_json_wasm::from_str(&serde_json_wasm::to_string(&filter).unwrap()).unwrap();
+        assert_eq!(decoded, filter);
+
+        let items: Vec<TransactionFilterItem> = serde_json_wasm::from_str(&json).unwrap();
+        assert_eq!(items[5].value, TransactionFilterValue::Int(u64::MAX));
+        assert!(json.contains(r#""value":18446744073709551615"#));
 
-            assert_eq!(delegations, ts.expected_result)
+        // numeric strings are still strings
+        for v in ["100", "18446744073709551616"] {
+            let value = TransactionFilterValue::String(v.to_string());
+            let json = serde_json_wasm::to_string(&value).unwrap();
+            assert_eq!(json, format!("\"{}\"", v));
+            let decoded: TransactionFilterValue = serde_json_wasm::from_str(&json).unwrap();
+            assert_eq!(decoded, value);
+            let decoded: TransactionFilterValue = schemars::_serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded, value);
         }
+
+        // negative numbers and integers greater than u64 aren't valid values
+        assert!(
+            serde_json_wasm::from_str::<TransactionFilterValue>("18446744073709551616").is_err()
+        );
+        assert!(serde_json_wasm::from_str::<TransactionFilterValue>("-1").is_err());
+
+        for field in [
+            "",
+            "transfer",
+            ".recipient",
+            "transfer.",
+            "transfer..recipient",
+            "transfer.recipient = 'x' OR tx.height",
+            "transfer recipient",
+        ] {
+            assert!(
+                TxFilter::new().eq(field, "value").is_err(),
+                "field {:?} must be invalid",
+                field
+            );
+        }
+
+        assert!(TxFilter::new()
+            .eq(RECIPIENT_FIELD, "osmo1' OR transfer.sender = 'osmo1")
+            .is_err());
     }
 }
@@ -1,8 +1,16 @@
-use cosmos_sdk_proto::cosmos::base::abci::v1beta1::{MsgData, TxMsgData};
-use cosmwasm_std::{Binary, Deps, Reply, StdError, StdResult};
+use crate::bindings::query::{
+    InterchainQueries, InterchainTxsParams, QueryInterchainTxsParamsResponse,
+};
+use crate::interchain_txs::types::{IcaMsgResponse, TxMsgData};
+use crate::{NeutronError, NeutronResult};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::MsgData;
+use cosmwasm_std::{from_binary, Binary, Deps, Reply, StdError, StdResult};
 use prost::{DecodeError, Message};
+use serde::de::DeserializeOwned;
 
-/// Parse acknowledgement into Vec<MsgData> structure
+/// Parse acknowledgement into Vec<MsgData> structure.
+/// Both pre Cosmos SDK v0.46 (**data**) and v0.46+ (**msg_responses**) layouts are supported,
+/// for the latter **msg_type** of each item is a type url of the message response
 pub fn parse_response(data: Binary) -> StdResult<Vec<MsgData>> {
     let result = Binary::from_base64(&data.to_string())?;
     let msg_data: Result<TxMsgData, DecodeError> = TxMsgData::decode(result.as_slice());
@@ -13,10 +21,19 @@ pub fn parse_response(data: Binary) -> StdResult<Vec<MsgData>> {
                 e
             )))
         }
-        Ok(msg) => Ok(msg.data),
+        Ok(msg) => Ok(msg.into_msg_data()),
     }
 }
 
+/// Parse acknowledgement into Vec<IcaMsgResponse> typed responses.
+/// Responses of the messages unknown to the SDK are returned as **IcaMsgResponse::Raw**
+pub fn decode_acknowledgement_response(data: Binary) -> NeutronResult<Vec<IcaMsgResponse>> {
+    parse_response(data)?
+        .iter()
+        .map(|item| IcaMsgResponse::decode(&item.msg_type, &item.data))
+        .collect()
+}
+
 /// Parse protobuff any item into T structure
 pub fn parse_item<T: prost::Message + Default>(item: &Vec<u8>) -> StdResult<T> {
     let res = T::decode(item.as_slice());
@@ -67,6 +84,47 @@ pub fn parse_sequence(deps: Deps, msg: Reply) -> StdResult<(String, u64)> {
     )))
 }
 
+/// Decodes JSON encoded response of Neutron custom message (e.g. **MsgIbcTransferResponse**)
+/// from the data of submessage reply
+pub fn decode_message_response<T: DeserializeOwned>(msg: Reply) -> StdResult<T> {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .data
+        .ok_or_else(|| StdError::generic_err("no data in reply"))?;
+
+    from_binary(&data)
+}
+
+/// Queries params of the interchaintxs module (max messages count in SubmitTx, etc.)
+pub fn get_interchain_txs_params(
+    deps: Deps<InterchainQueries>,
+) -> NeutronResult<InterchainTxsParams> {
+    let query = InterchainQueries::InterchainTxsParams {};
+
+    let res: QueryInterchainTxsParamsResponse = deps.querier.query(&query.into())?;
+    Ok(res.params)
+}
+
+/// Checks that **msgs_count** messages fit into a single **SubmitTx** message
+/// according to **msg_submit_tx_max_messages** of the interchaintxs module
+pub fn check_submit_tx_msgs_count(
+    deps: Deps<InterchainQueries>,
+    msgs_count: usize,
+) -> NeutronResult<()> {
+    let params = get_interchain_txs_params(deps)?;
+
+    if msgs_count as u64 > params.msg_submit_tx_max_messages {
+        return Err(NeutronError::TooManySubmitTxMessages {
+            max: params.msg_submit_tx_max_messages,
+            actual: msgs_count as u64,
+        });
+    }
+
+    Ok(())
+}
+
 const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
 const ICA_OWNER_DELIMITER: &str = ".";
 
@@ -0,0 +1,348 @@
+use crate::bindings::types::ToProtobufAny;
+use crate::{NeutronError, NeutronResult};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::MsgData;
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward;
+use cosmos_sdk_proto::cosmos::gov::v1beta1::{MsgVote, MsgVoteResponse};
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+    MsgBeginRedelegate, MsgBeginRedelegateResponse, MsgDelegate, MsgDelegateResponse,
+    MsgUndelegate, MsgUndelegateResponse,
+};
+use cosmos_sdk_proto::ibc::applications::transfer::v1::MsgTransfer;
+use cosmwasm_std::{Binary, Coin, StdError, Timestamp, Uint128};
+use osmosis_std::types::osmosis::gamm::v1beta1::{
+    MsgSwapExactAmountIn, MsgSwapExactAmountInResponse,
+};
+use prost::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Suffix of the type url of a message response (e.g. `/cosmos.staking.v1beta1.MsgDelegateResponse`)
+const RESPONSE_TYPE_URL_SUFFIX: &str = "Response";
+
+/// TxMsgData defines a list of MsgData and MsgResponses of a transaction.
+/// Unlike the definition in cosmos-sdk-proto, it includes the **msg_responses** field introduced in Cosmos SDK v0.46
+#[derive(Clone, PartialEq, Message)]
+pub struct TxMsgData {
+    /// **data** is a list of message results, it's deprecated and empty since Cosmos SDK v0.46
+    #[prost(message, repeated, tag = "1")]
+    pub data: Vec<MsgData>,
+
+    /// **msg_responses** is a list of message responses, it's populated since Cosmos SDK v0.46
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<prost_types::Any>,
+}
+
+impl TxMsgData {
+    /// Returns message results regardless of the layout used by the host chain.
+    /// **msg_responses** are converted into MsgData with the response type url as **msg_type**
+    pub fn into_msg_data(self) -> Vec<MsgData> {
+        if self.msg_responses.is_empty() {
+            return self.data;
+        }
+
+        self.msg_responses
+            .into_iter()
+            .map(|any| MsgData {
+                msg_type: any.type_url,
+                data: any.value,
+            })
+            .collect()
+    }
+}
+
+/// MsgTransferResponse defines the Msg/Transfer response type of ibc-go.
+/// The **sequence** field is not defined in the older ibc-go versions, it's decoded as 0 in that case
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgTransferResponse {
+    /// **sequence** is a sequence number of the sent IBC packet
+    #[prost(uint64, tag = "1")]
+    pub sequence: u64,
+}
+
+/// MsgWithdrawDelegatorRewardResponse defines the Msg/WithdrawDelegatorReward response type.
+/// The **amount** field is not defined in Cosmos SDK prior to v0.46, it's decoded as empty in that case
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgWithdrawDelegatorRewardResponse {
+    /// **amount** is the withdrawn rewards
+    #[prost(message, repeated, tag = "1")]
+    pub amount: Vec<CosmosCoin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes a typed response of a message executed on remote chain by an interchain account
+pub enum IcaMsgResponse {
+    /// Response of **/cosmos.staking.v1beta1.MsgDelegate**
+    Delegate {},
+
+    /// Response of **/cosmos.staking.v1beta1.MsgUndelegate**
+    Undelegate {
+        /// **completion_time** is the time when the undelegated tokens become liquid
+        completion_time: Timestamp,
+    },
+
+    /// Response of **/cosmos.staking.v1beta1.MsgBeginRedelegate**
+    BeginRedelegate {
+        /// **completion_time** is the time when the redelegation completes
+        completion_time: Timestamp,
+    },
+
+    /// Response of **/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward**
+    WithdrawDelegatorReward {
+        /// **amount** is the withdrawn rewards (always empty on Cosmos SDK prior to v0.46)
+        amount: Vec<Coin>,
+    },
+
+    /// Response of **/cosmos.bank.v1beta1.MsgSend**
+    Send {},
+
+    /// Response of **/ibc.applications.transfer.v1.MsgTransfer**
+    IbcTransfer {
+        /// **sequence** is a sequence number of the sent IBC packet (0 on older ibc-go versions)
+        sequence: u64,
+    },
+
+    /// Response of **/cosmos.gov.v1beta1.MsgVote**
+    Vote {},
+
+    /// Response of **/osmosis.gamm.v1beta1.MsgSwapExactAmountIn**
+    OsmosisSwapExactAmountIn {
+        /// **token_out_amount** is the amount of tokens received from the swap
+        token_out_amount: Uint128,
+    },
+
+    /// Response of a message which is not known to the SDK
+    Raw {
+        /// **msg_type** is the type of the message as it is reported by the remote chain
+        msg_type: String,
+
+        /// **data** is the protobuf encoded message response
+        data: Binary,
+    },
+}
+
+impl IcaMsgResponse {
+    /// Decodes a message response into typed **IcaMsgResponse**:
+    /// * **msg_type** is a type url of the message (e.g. `/cosmos.staking.v1beta1.MsgDelegate`) or
+    /// its response (e.g. `/cosmos.staking.v1beta1.MsgDelegateResponse`);
+    /// * **data** is the protobuf encoded message response.
+    ///
+    /// Returns **IcaMsgResponse::Raw** for the unknown message types
+    pub fn decode(msg_type: &str, data: &[u8]) -> NeutronResult<Self> {
+        let request_type = msg_type
+            .strip_suffix(RESPONSE_TYPE_URL_SUFFIX)
+            .unwrap_or(msg_type);
+
+        let response = if request_type == MsgDelegate::TYPE_URL {
+            MsgDelegateResponse::decode(data)?;
+            IcaMsgResponse::Delegate {}
+        } else if request_type == MsgUndelegate::TYPE_URL {
+            let resp = MsgUndelegateResponse::decode(data)?;
+            let completion_time = resp.completion_time.ok_or_else(|| {
+                NeutronError::Std(StdError::generic_err("failed to get completion time"))
+            })?;
+            IcaMsgResponse::Undelegate {
+                completion_time: proto_timestamp(completion_time.seconds, completion_time.nanos)?,
+            }
+        } else if request_type == MsgBeginRedelegate::TYPE_URL {
+            let resp = MsgBeginRedelegateResponse::decode(data)?;
+            let completion_time = resp.completion_time.ok_or_else(|| {
+                NeutronError::Std(StdError::generic_err("failed to get completion time"))
+            })?;
+            IcaMsgResponse::BeginRedelegate {
+                completion_time: proto_timestamp(completion_time.seconds, completion_time.nanos)?,
+            }
+        } else if request_type == MsgWithdrawDelegatorReward::TYPE_URL {
+            let resp = MsgWithdrawDelegatorRewardResponse::decode(data)?;
+            IcaMsgResponse::WithdrawDelegatorReward {
+                amount: resp
+                    .amount
+                    .into_iter()
+                    .map(|c| -> NeutronResult<Coin> {
+                        Ok(Coin::new(Uint128::from_str(&c.amount)?.u128(), c.denom))
+                    })
+                    .collect::<NeutronResult<Vec<Coin>>>()?,
+            }
+        } else if request_type == MsgSend::TYPE_URL {
+            MsgSendResponse::decode(data)?;
+            IcaMsgResponse::Send {}
+        } else if request_type == MsgTransfer::TYPE_URL {
+            let resp = MsgTransferResponse::decode(data)?;
+            IcaMsgResponse::IbcTransfer {
+                sequence: resp.sequence,
+            }
+        } else if request_type == MsgVote::TYPE_URL {
+            MsgVoteResponse::decode(data)?;
+            IcaMsgResponse::Vote {}
+        } else if request_type == MsgSwapExactAmountIn::TYPE_URL {
+            let resp = MsgSwapExactAmountInResponse::decode(data)?;
+            IcaMsgResponse::OsmosisSwapExactAmountIn {
+                token_out_amount: Uint128::from_str(&resp.token_out_amount)?,
+            }
+        } else {
+            IcaMsgResponse::Raw {
+                msg_type: msg_type.to_string(),
+                data: Binary::from(data),
+            }
+        };
+
+        Ok(response)
+    }
+}
+
+/// Converts protobuf timestamp into **Timestamp**.
+/// Returns an error for timestamps before the unix epoch and for nanos out of the [0, 999_999_999] range
+pub(crate) fn proto_timestamp(seconds: i64, nanos: i32) -> NeutronResult<Timestamp> {
+    match (u64::try_from(seconds), u32::try_from(nanos)) {
+        (Ok(s), Ok(n)) if n < 1_000_000_000 => Ok(Timestamp::from_seconds(s).plus_nanos(n.into())),
+        _ => Err(NeutronError::InvalidTimestamp { seconds, nanos }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        proto_timestamp, IcaMsgResponse, MsgTransferResponse, MsgWithdrawDelegatorRewardResponse,
+        TxMsgData,
+    };
+    use crate::interchain_txs::helpers::decode_acknowledgement_response;
+    use crate::NeutronError;
+    use cosmos_sdk_proto::cosmos::base::abci::v1beta1::MsgData;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
+    use cosmos_sdk_proto::cosmos::staking::v1beta1::MsgUndelegateResponse;
+    use cosmwasm_std::{coin, Binary, Timestamp};
+    use prost::Message;
+
+    #[test]
+    fn test_decode_ica_msg_response() {
+        let undelegate = MsgUndelegateResponse {
+            completion_time: Some(prost_types::Timestamp {
+                seconds: 1_000,
+                nanos: 5,
+            }),
+        };
+        let expected = IcaMsgResponse::Undelegate {
+            completion_time: Timestamp::from_seconds(1_000).plus_nanos(5),
+        };
+        // both pre SDK 0.46 (request type url) and 0.46+ (response type url) layouts are supported
+        assert_eq!(
+            IcaMsgResponse::decode(
+                "/cosmos.staking.v1beta1.MsgUndelegate",
+                &undelegate.encode_to_vec()
+            )
+            .unwrap(),
+            expected
+        );
+        assert_eq!(
+            IcaMsgResponse::decode(
+                "/cosmos.staking.v1beta1.MsgUndelegateResponse",
+                &undelegate.encode_to_vec()
+            )
+            .unwrap(),
+            expected
+        );
+
+        let rewards = MsgWithdrawDelegatorRewardResponse {
+            amount: vec![CosmosCoin {
+                denom: "stake".to_string(),
+                amount: "100".to_string(),
+            }],
+        };
+        assert_eq!(
+            IcaMsgResponse::decode(
+                "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorRewardResponse",
+                &rewards.encode_to_vec()
+            )
+            .unwrap(),
+            IcaMsgResponse::WithdrawDelegatorReward {
+                amount: vec![coin(100, "stake")]
+            }
+        );
+
+        // empty response of the older ibc-go versions
+        assert_eq!(
+            IcaMsgResponse::decode("/ibc.applications.transfer.v1.MsgTransfer", &[]).unwrap(),
+            IcaMsgResponse::IbcTransfer { sequence: 0 }
+        );
+        assert_eq!(
+            IcaMsgResponse::decode(
+                "/ibc.applications.transfer.v1.MsgTransferResponse",
+                &MsgTransferResponse { sequence: 7 }.encode_to_vec()
+            )
+            .unwrap(),
+            IcaMsgResponse::IbcTransfer { sequence: 7 }
+        );
+
+        assert_eq!(
+            IcaMsgResponse::decode("/cosmos.unknown.v1beta1.MsgUnknown", &[1, 2, 3]).unwrap(),
+            IcaMsgResponse::Raw {
+                msg_type: "/cosmos.unknown.v1beta1.MsgUnknown".to_string(),
+                data: Binary::from(vec![1, 2, 3]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_acknowledgement_response_layouts() {
+        let transfer = MsgTransferResponse { sequence: 3 }.encode_to_vec();
+
+        // Cosmos SDK prior to v0.46
+        let legacy = TxMsgData {
+            data: vec![
+                MsgData {
+                    msg_type: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+                    data: vec![],
+                },
+                MsgData {
+                    msg_type: "/ibc.applications.transfer.v1.MsgTransfer".to_string(),
+                    data: transfer.clone(),
+                },
+            ],
+            msg_responses: vec![],
+        };
+        // Cosmos SDK v0.46+
+        let current = TxMsgData {
+            data: vec![],
+            msg_responses: vec![
+                prost_types::Any {
+                    type_url: "/cosmos.staking.v1beta1.MsgDelegateResponse".to_string(),
+                    value: vec![],
+                },
+                prost_types::Any {
+                    type_url: "/ibc.applications.transfer.v1.MsgTransferResponse".to_string(),
+                    value: transfer,
+                },
+            ],
+        };
+
+        let expected = vec![
+            IcaMsgResponse::Delegate {},
+            IcaMsgResponse::IbcTransfer { sequence: 3 },
+        ];
+        for tx_msg_data in [legacy, current] {
+            assert_eq!(
+                decode_acknowledgement_response(Binary::from(tx_msg_data.encode_to_vec())).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_proto_timestamp() {
+        assert_eq!(
+            proto_timestamp(1_650_000_000, 500).unwrap(),
+            Timestamp::from_seconds(1_650_000_000).plus_nanos(500)
+        );
+        assert_eq!(proto_timestamp(0, 0).unwrap(), Timestamp::from_nanos(0));
+
+        for (seconds, nanos) in [(-1, 0), (1_650_000_000, -1), (1_650_000_000, 1_000_000_000)] {
+            assert_eq!(
+                proto_timestamp(seconds, nanos).unwrap_err(),
+                NeutronError::InvalidTimestamp { seconds, nanos }
+            );
+        }
+    }
+}
@@ -10,6 +10,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+// allows to use the derive macros generating **::neutron_sdk** paths inside the crate itself
+extern crate self as neutron_sdk;
+
 pub mod bindings;
 mod errors;
 pub mod interchain_queries;